@@ -1,10 +1,9 @@
-use crate::domain::time_point::TimePoint;
+use crate::domain::{clock::Clock, country::Country, time_point::{TimePoint, TimePointError}};
 
 #[derive(Debug, Clone)]
 pub struct Aircraft {
     operator: String,
-    nationality_code: String,
-    reg_id: String,
+    registration: String, // full registration as painted, nationality mark included, e.g. "B-2627" or "PR-XTA"
     manufacturer: String,
     model: String,
     sub_model: String,
@@ -13,12 +12,19 @@ pub struct Aircraft {
 }
 
 impl Aircraft {
-    pub fn new(operator: String, nationality_code: String, reg_id: String, manufacturer: String, model: String, sub_model: String, in_service_date: TimePoint, cabin_config: String) -> Self {
-        return Aircraft { operator: operator, nationality_code: nationality_code, reg_id: reg_id, manufacturer: manufacturer, model: model, sub_model: sub_model, in_service_date: in_service_date, cabin_config: cabin_config };
+    pub fn new(operator: String, registration: String, manufacturer: String, model: String, sub_model: String, in_service_date: TimePoint, cabin_config: String) -> Result<Self, TimePointError> {
+        in_service_date.validate()?;
+        let registration = registration.trim().to_ascii_uppercase();
+        return Ok(Aircraft { operator, registration, manufacturer, model, sub_model, in_service_date, cabin_config });
     }
 
     pub fn get_reg_code(&self) -> String {
-        return format!("{}{}", self.nationality_code, self.reg_id);
+        return self.registration.to_string();
+    }
+
+    // country of registry read from the nationality mark, None when the mark is not known
+    pub fn get_nationality(&self) -> Option<Country> {
+        return Country::from_registration(&self.registration);
     }
 
     pub fn get_model(&self) -> String {
@@ -33,9 +39,8 @@ impl Aircraft {
         return self.operator.to_string();
     }
 
-    pub fn get_age(&self) -> f64 {
-        let mut now = TimePoint::new(0, 0, 0, 0, 0, 0, 0);
-        now.get_now();
+    pub fn get_age(&self, clock: &dyn Clock) -> f64 {
+        let now = clock.now();
         let mut month_diff = now.month - self.in_service_date.month;
         let mut year_diff = now.year - self.in_service_date.year;
         if month_diff < 0 {
@@ -45,32 +50,57 @@ impl Aircraft {
         return year_diff as f64 + (month_diff as f64 / 12.0 * 10.0).round() / 10.0;
     }
 
-    pub fn to_string(&self) -> String {
+    pub fn to_string(&self, clock: &dyn Clock) -> String {
+        let age = self.get_age(clock);
         return format!("{} {} {}: Registed with {}, serveced {} {}, cabin configured as {}.",
-         self.operator, self.manufacturer, self.get_model(), self.get_reg_code(), self.get_age(), if self.get_age() <= 1.0 {"year"} else {"years"}, self.cabin_config);
+         self.operator, self.manufacturer, self.get_model(), self.get_reg_code(), age, if age <= 1.0 {"year"} else {"years"}, self.cabin_config);
     }
 }
 
 #[cfg(test)]
 mod aircraft_tests {
     use super::*;
+    use crate::domain::clock::FixedClock;
 
     fn get_test_use() -> Aircraft {
         return Aircraft::new(
             "Air China".to_string(),
-            "B".to_string(),
-            "2627".to_string(),
+            "B-2627".to_string(),
+            "Boeing".to_string(),
+            "747".to_string(),
+            "400".to_string(),
+            TimePoint::new(2010, 9, 30, 0, 0, 0, 8 * 60).unwrap(),
+            "F6C12Y128".to_string()
+        ).unwrap();
+    }
+
+    #[test]
+    fn test_new_rejects_invalid_in_service_date() {
+        let mut in_service_date = TimePoint::new(2010, 9, 30, 0, 0, 0, 8 * 60).unwrap();
+        in_service_date.month = 13;
+        let aircraft = Aircraft::new(
+            "Air China".to_string(),
+            "B-2627".to_string(),
             "Boeing".to_string(),
             "747".to_string(),
             "400".to_string(),
-            TimePoint::new(2010, 9, 30, 0, 0, 0, 8),
+            in_service_date,
             "F6C12Y128".to_string()
         );
+        assert_eq!(aircraft.unwrap_err(), TimePointError::InvalidMonth(13));
     }
 
     #[test]
     fn test_get_reg_code() {
-        assert_eq!(get_test_use().get_reg_code(), "B2627");
+        assert_eq!(get_test_use().get_reg_code(), "B-2627");
+        assert_eq!(get_test_use().get_nationality().unwrap().get_name(), "China");
+        let with_registration = |registration: &str| Aircraft::new("".into(), registration.into(), "Embraer".into(), "E195".into(), "E2".into(),
+            TimePoint::new_date(2021, 1, 1, 0).unwrap(), "Y136".into()).unwrap();
+        assert_eq!(with_registration("PR-XTA").get_reg_code(), "PR-XTA");
+        assert_eq!(with_registration("pr-xta").get_nationality().unwrap().get_alpha2(), "BR");
+        assert_eq!(with_registration("VQ-BIR").get_nationality().unwrap().get_alpha2(), "BM");
+        assert_eq!(with_registration("QQ-123").get_reg_code(), "QQ-123");
+        assert_eq!(with_registration("QQ-123").get_nationality(), None);
     }
 
     #[test]
@@ -88,14 +118,20 @@ mod aircraft_tests {
         assert_eq!(get_test_use().get_operator(), "Air China");
     }
 
+    fn get_test_clock() -> FixedClock {
+        return FixedClock::new(TimePoint::new(2025, 10, 18, 12, 0, 0, 8 * 60).unwrap());
+    }
+
     #[test]
     fn test_get_age() {
-        assert_eq!(get_test_use().get_age(), 15.1);
+        assert_eq!(get_test_use().get_age(&get_test_clock()), 15.1);
+        let later = FixedClock::new(TimePoint::new(2031, 8, 1, 0, 0, 0, 8 * 60).unwrap());
+        assert_eq!(get_test_use().get_age(&later), 20.9);
     }
 
     #[test]
     fn test_to_string() {
-        assert_eq!(get_test_use().to_string(), "Air China Boeing 747-400: Registed with B2627, serveced 15.1 years, cabin configured as F6C12Y128.");
+        assert_eq!(get_test_use().to_string(&get_test_clock()), "Air China Boeing 747-400: Registed with B-2627, serveced 15.1 years, cabin configured as F6C12Y128.");
     }
 }
 
@@ -1,25 +1,41 @@
+use std::fmt;
+
+use crate::domain::{airline_code::{AccountingCode, IataAirlineCode, IcaoAirlineCode}, country::Country};
+
 #[derive(Debug, Clone)]
 pub struct Airline {
-    code: String, // 2-digi code
-    identifier: String, // 3-digi code
+    code: IataAirlineCode,
+    identifier: IcaoAirlineCode,
     call_sign: String,
     name: String,
-    country: String,
+    country: Country,
     alliance: String, // airlines alliance, e.g. Oneworld, Star Alliance, SkyTeam
     frequent_flyer_program: String,
-    etkt_prefix: String
+    etkt_prefix: AccountingCode
 }
 
 impl Airline {
-    pub fn new(code: String, identifier: String, call_sign: String, name: String, country: String, alliance: String, frequent_flyer_program: String, etkt_prefix: String) -> Self {
-        return Airline { code, identifier, call_sign, name, country, alliance, frequent_flyer_program, etkt_prefix };
+    #[allow(clippy::too_many_arguments)] // one argument per field, like the other domain constructors
+    pub fn new(code: String, identifier: String, call_sign: String, name: String, country: Country, alliance: String, frequent_flyer_program: String, etkt_prefix: String) -> Result<Self, AirlineError> {
+        let code = IataAirlineCode::new(&code)?;
+        let identifier = IcaoAirlineCode::new(&identifier)?;
+        let etkt_prefix = AccountingCode::new(&etkt_prefix)?;
+        return Ok(Airline { code, identifier, call_sign, name, country, alliance, frequent_flyer_program, etkt_prefix });
     }
 
     pub fn get_code(&self) -> &str {
-        return &self.code;
+        return self.code.as_str();
     }
 
     pub fn get_identifier(&self) -> &str {
+        return self.identifier.as_str();
+    }
+
+    pub fn get_iata(&self) -> &IataAirlineCode {
+        return &self.code;
+    }
+
+    pub fn get_icao(&self) -> &IcaoAirlineCode {
         return &self.identifier;
     }
 
@@ -31,8 +47,8 @@ impl Airline {
         return &self.name;
     }
 
-    pub fn get_country(&self) -> &str {
-        return &self.country;
+    pub fn get_country(&self) -> Country {
+        return self.country;
     }
 
     pub fn get_alliance(&self) -> &str {
@@ -44,10 +60,34 @@ impl Airline {
     }
 
     pub fn get_etkt_prefix(&self) -> &str {
+        return self.etkt_prefix.as_str();
+    }
+
+    pub fn get_accounting_code(&self) -> &AccountingCode {
         return &self.etkt_prefix;
     }
 }
 
+#[derive(PartialEq, Eq, Debug, Clone)]
+#[allow(clippy::enum_variant_names)] // named like AirportError
+pub enum AirlineError {
+    InvalidIataCode(String),
+    InvalidIcaoCode(String),
+    InvalidAccountingCode(String)
+}
+
+impl fmt::Display for AirlineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            AirlineError::InvalidIataCode(code) => write!(f, "\"{}\" is not a 2-character IATA airline designator", code),
+            AirlineError::InvalidIcaoCode(code) => write!(f, "\"{}\" is not a 3-letter ICAO airline designator", code),
+            AirlineError::InvalidAccountingCode(code) => write!(f, "\"{}\" is not a 3-digit accounting code", code),
+        };
+    }
+}
+
+impl std::error::Error for AirlineError {}
+
 #[cfg(test)]
 mod test_airline {
     use super::*;
@@ -56,22 +96,34 @@ mod test_airline {
     fn test_airline_creation() {
         let airline = Airline::new(
             "AA".into(),
-            "American Airlines".into(),
+            "AAL".into(),
             "American".into(),
             "American Airlines".into(),
-            "United States".into(),
+            Country::parse("United States").unwrap(),
             "Oneworld".into(),
             "AAdvantage".into(),
             "001".into(),
-        );
+        ).unwrap();
 
         assert_eq!(airline.get_code(), "AA");
-        assert_eq!(airline.get_identifier(), "American Airlines");
+        assert_eq!(airline.get_identifier(), "AAL");
         assert_eq!(airline.get_call_sign(), "American");
         assert_eq!(airline.get_name(), "American Airlines");
-        assert_eq!(airline.get_country(), "United States");
+        assert_eq!(airline.get_country().get_alpha2(), "US");
         assert_eq!(airline.get_alliance(), "Oneworld");
         assert_eq!(airline.get_frequent_flyer_program(), "AAdvantage");
         assert_eq!(airline.get_etkt_prefix(), "001");
     }
+
+    #[test]
+    fn test_designators_are_checked() {
+        let new_with = |code: &str, identifier: &str, etkt_prefix: &str| Airline::new(
+            code.into(), identifier.into(), "SICHUAN".into(), "Sichuan Airlines".into(), Country::parse("CN").unwrap(),
+            "".into(), "Golden Panda".into(), etkt_prefix.into());
+
+        assert_eq!(new_with("3u", "csc", "876").unwrap().get_iata().as_str(), "3U");
+        assert_eq!(new_with("3U", "Sichuan Airlines", "876").unwrap_err(), AirlineError::InvalidIcaoCode("Sichuan Airlines".into()));
+        assert_eq!(new_with("CSC", "CSC", "876").unwrap_err(), AirlineError::InvalidIataCode("CSC".into()));
+        assert_eq!(new_with("3U", "CSC", "87").unwrap_err().to_string(), "\"87\" is not a 3-digit accounting code");
+    }
 }
@@ -0,0 +1,125 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::domain::airline::AirlineError;
+
+// 2-character IATA airline designator, e.g. "CA" or "3U", kept in upper case; two digits are never issued
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone)]
+pub struct IataAirlineCode(String);
+
+// 3-letter ICAO airline designator, e.g. "CCA", kept in upper case
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone)]
+pub struct IcaoAirlineCode(String);
+
+// 3-digit IATA accounting code, the airline prefix of e-ticket numbers, e.g. "999" for Air China
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone)]
+pub struct AccountingCode(String);
+
+impl IataAirlineCode {
+    pub fn new(code: &str) -> Result<Self, AirlineError> {
+        let code = code.trim();
+        if code.len() != 2 || !code.chars().all(|c| c.is_ascii_alphanumeric()) || code.chars().all(|c| c.is_ascii_digit()) {
+            return Err(AirlineError::InvalidIataCode(code.to_string()));
+        }
+        return Ok(IataAirlineCode(code.to_ascii_uppercase()));
+    }
+
+    pub fn as_str(&self) -> &str {
+        return &self.0;
+    }
+}
+
+impl IcaoAirlineCode {
+    pub fn new(code: &str) -> Result<Self, AirlineError> {
+        let code = code.trim();
+        if code.len() != 3 || !code.chars().all(|c| c.is_ascii_alphabetic()) {
+            return Err(AirlineError::InvalidIcaoCode(code.to_string()));
+        }
+        return Ok(IcaoAirlineCode(code.to_ascii_uppercase()));
+    }
+
+    pub fn as_str(&self) -> &str {
+        return &self.0;
+    }
+}
+
+impl AccountingCode {
+    pub fn new(code: &str) -> Result<Self, AirlineError> {
+        let code = code.trim();
+        if code.len() != 3 || !code.chars().all(|c| c.is_ascii_digit()) {
+            return Err(AirlineError::InvalidAccountingCode(code.to_string()));
+        }
+        return Ok(AccountingCode(code.to_string()));
+    }
+
+    pub fn as_str(&self) -> &str {
+        return &self.0;
+    }
+}
+
+impl FromStr for IataAirlineCode {
+    type Err = AirlineError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        return IataAirlineCode::new(s);
+    }
+}
+
+impl FromStr for IcaoAirlineCode {
+    type Err = AirlineError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        return IcaoAirlineCode::new(s);
+    }
+}
+
+impl FromStr for AccountingCode {
+    type Err = AirlineError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        return AccountingCode::new(s);
+    }
+}
+
+impl fmt::Display for IataAirlineCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "{}", self.0);
+    }
+}
+
+impl fmt::Display for IcaoAirlineCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "{}", self.0);
+    }
+}
+
+impl fmt::Display for AccountingCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "{}", self.0);
+    }
+}
+
+#[cfg(test)]
+mod test_airline_code {
+    use super::*;
+
+    #[test]
+    fn test_iata_code() {
+        assert_eq!(IataAirlineCode::new(" ca ").unwrap().as_str(), "CA");
+        assert_eq!("3u".parse::<IataAirlineCode>().unwrap().to_string(), "3U");
+        assert_eq!(IataAirlineCode::new("U2").unwrap().as_str(), "U2");
+        assert_eq!(IataAirlineCode::new("33"), Err(AirlineError::InvalidIataCode("33".into())));
+        assert_eq!(IataAirlineCode::new("CCA"), Err(AirlineError::InvalidIataCode("CCA".into())));
+        assert_eq!(IataAirlineCode::new("C-"), Err(AirlineError::InvalidIataCode("C-".into())));
+    }
+
+    #[test]
+    fn test_icao_and_accounting_code() {
+        assert_eq!(IcaoAirlineCode::new("aal").unwrap().as_str(), "AAL");
+        assert_eq!(IcaoAirlineCode::new("American Airlines"), Err(AirlineError::InvalidIcaoCode("American Airlines".into())));
+        assert_eq!(IcaoAirlineCode::new("CS1"), Err(AirlineError::InvalidIcaoCode("CS1".into())));
+        assert_eq!(AccountingCode::new("001").unwrap().to_string(), "001");
+        assert_eq!(AccountingCode::new("01"), Err(AirlineError::InvalidAccountingCode("01".into())));
+        assert_eq!(AccountingCode::new("A01"), Err(AirlineError::InvalidAccountingCode("A01".into())));
+    }
+}
@@ -1,23 +1,78 @@
+use std::fmt;
+
+use chrono_tz::Tz;
+
+use crate::domain::{airport_code::{IataAirportCode, IcaoAirportCode}, country::Country, geodesic::{self, Distance, DistanceMethod}, time_point::{DstPolicy, Precision, TimePoint, TimePointError}};
+
+// lowest: Bar Yehuda (MTZ) at about -1,266 ft, highest: Daocheng Yading (DCY) at about 14,472 ft
+pub const MIN_ELEVATION_FT: i32 = -1500;
+pub const MAX_ELEVATION_FT: i32 = 15000;
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Coordinates {
+    pub latitude: f64, // unit: degree, north positive
+    pub longitude: f64 // unit: degree, east positive
+}
+
+impl Coordinates {
+    pub fn new(latitude: f64, longitude: f64) -> Result<Self, AirportError> {
+        if !latitude.is_finite() || !(-90.0..=90.0).contains(&latitude) {
+            return Err(AirportError::InvalidLatitude(latitude));
+        }
+        if !longitude.is_finite() || !(-180.0..=180.0).contains(&longitude) {
+            return Err(AirportError::InvalidLongitude(longitude));
+        }
+        return Ok(Coordinates { latitude, longitude });
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Airport {
-    iata_code: String, // 3-digi code
-    icao_code: String, // 4-digi code
+    iata_code: IataAirportCode,
+    icao_code: IcaoAirportCode,
     name: String,
     city: String,
     province: String,
-    country: String
+    country: Country,
+    coordinates: Coordinates,
+    elevation: i32, // unit: ft above mean sea level
+    zone: Option<Tz>, // IANA time zone, None when not known
+    terminals: Vec<String>, // e.g. "T1", "T2", "T3"; empty when not known
+    valid_from: Option<TimePoint>, // first day in service, None when open since before our records
+    valid_until: Option<TimePoint>, // first day no longer in service, None while still open
+    successors: Vec<IataAirportCode> // airports that took over when this one closed, e.g. TXL -> BER
 }
 
 impl Airport {
-    pub fn new(iata_code: String, icao_code: String, name: String, city: String, province: String, country: String) -> Self {
-        return Airport { iata_code, icao_code, name, city, province, country };
+    #[allow(clippy::too_many_arguments)] // one argument per field, like the other domain constructors
+    pub fn new(iata_code: String, icao_code: String, name: String, city: String, province: String, country: Country, latitude: f64, longitude: f64, elevation: i32) -> Result<Self, AirportError> {
+        let iata_code = IataAirportCode::new(&iata_code)?;
+        let icao_code = IcaoAirportCode::new(&icao_code)?;
+        // a territory may use its state's region, e.g. Jersey is EGJJ
+        if let Some(region_country) = icao_code.region_country()
+            && country != region_country && country.get_sovereign() != Some(region_country) {
+            return Err(AirportError::IcaoRegionMismatch { icao_code: icao_code.to_string(), country, region_country });
+        }
+        let coordinates = Coordinates::new(latitude, longitude)?;
+        if !(MIN_ELEVATION_FT..=MAX_ELEVATION_FT).contains(&elevation) {
+            return Err(AirportError::ElevationOutOfRange(elevation));
+        }
+        return Ok(Airport { iata_code, icao_code, name, city, province, country, coordinates, elevation, zone: None, terminals: Vec::new(), valid_from: None, valid_until: None, successors: Vec::new() });
     }
 
     pub fn get_iata_code(&self) -> &str {
-        return &self.iata_code;
+        return self.iata_code.as_str();
     }
 
     pub fn get_icao_code(&self) -> &str {
+        return self.icao_code.as_str();
+    }
+
+    pub fn get_iata(&self) -> &IataAirportCode {
+        return &self.iata_code;
+    }
+
+    pub fn get_icao(&self) -> &IcaoAirportCode {
         return &self.icao_code;
     }
 
@@ -33,49 +88,286 @@ impl Airport {
         return &self.province;
     }
 
-    pub fn get_country(&self) -> &str {
-        return &self.country;
+    pub fn get_country(&self) -> Country {
+        return self.country;
     }
 
     pub fn get_code(&self) -> String {
         return format!("{}/{}", self.iata_code, self.icao_code);
     }
+
+    pub fn get_coordinates(&self) -> Coordinates {
+        return self.coordinates;
+    }
+
+    // great-circle distance, None where Vincenty does not converge, see geodesic::distance
+    pub fn distance_to(&self, other: &Airport, method: DistanceMethod) -> Option<Distance> {
+        return geodesic::distance(&self.coordinates, &other.coordinates, method);
+    }
+
+    pub fn get_elevation(&self) -> i32 {
+        return self.elevation;
+    }
+
+    pub fn get_elevation_m(&self) -> f64 {
+        return self.elevation as f64 * 0.3048;
+    }
+
+    pub fn get_zone(&self) -> Option<Tz> {
+        return self.zone;
+    }
+
+    pub fn with_zone(mut self, zone: Tz) -> Self {
+        self.zone = Some(zone);
+        return self;
+    }
+
+    pub fn get_terminals(&self) -> &[String] {
+        return &self.terminals;
+    }
+
+    pub fn with_terminals(mut self, terminals: Vec<String>) -> Self {
+        self.terminals = terminals;
+        return self;
+    }
+
+    // any terminal is accepted while the airport's terminals are not known
+    pub fn has_terminal(&self, terminal: &str) -> bool {
+        return self.terminals.is_empty() || self.terminals.iter().any(|known| known.eq_ignore_ascii_case(terminal));
+    }
+
+    pub fn get_valid_from(&self) -> Option<&TimePoint> {
+        return self.valid_from.as_ref();
+    }
+
+    pub fn get_valid_until(&self) -> Option<&TimePoint> {
+        return self.valid_until.as_ref();
+    }
+
+    pub fn get_successors(&self) -> &[IataAirportCode] {
+        return &self.successors;
+    }
+
+    pub fn is_closed(&self) -> bool {
+        return self.valid_until.is_some();
+    }
+
+    // validity is by calendar date, from the opening day up to but excluding the closing day
+    pub fn with_validity(mut self, valid_from: Option<TimePoint>, valid_until: Option<TimePoint>) -> Result<Self, AirportError> {
+        if let (Some(from), Some(until)) = (&valid_from, &valid_until)
+            && day_number(from) >= day_number(until) {
+            return Err(AirportError::InvalidValidity { valid_from: from.to_string(), valid_until: until.to_string() });
+        }
+        self.valid_from = valid_from;
+        self.valid_until = valid_until;
+        return Ok(self);
+    }
+
+    pub fn with_successors(mut self, successors: Vec<IataAirportCode>) -> Self {
+        self.successors = successors;
+        return self;
+    }
+
+    // whether the airport served flights on that local date, e.g. HKG was Kai Tak until 6 July 1998
+    pub fn is_in_service_on(&self, date: &TimePoint) -> bool {
+        let day = day_number(date);
+        return self.valid_from.as_ref().is_none_or(|from| day_number(from) <= day)
+            && self.valid_until.as_ref().is_none_or(|until| day < day_number(until));
+    }
+
+    // an unbounded period counts as reaching to the end of time on that side
+    pub fn overlaps_in_service(&self, other: &Airport) -> bool {
+        let start = |airport: &Airport| airport.valid_from.as_ref().map_or(i64::MIN, day_number);
+        let end = |airport: &Airport| airport.valid_until.as_ref().map_or(i64::MAX, day_number);
+        return start(self) < end(other) && start(other) < end(self);
+    }
+
+    // wall-clock time read off this airport's clocks, the offset is filled in from its zone
+    pub fn local_time(&self, year: i32, month: i32, day: i32, hour: i32, minute: i32, second: i32) -> Result<TimePoint, TimePointError> {
+        return self.local_time_with(year, month, day, hour, minute, second, DstPolicy::Reject);
+    }
+
+    #[allow(clippy::too_many_arguments)] // date and time spelled out like TimePoint::new
+    pub fn local_time_with(&self, year: i32, month: i32, day: i32, hour: i32, minute: i32, second: i32, policy: DstPolicy) -> Result<TimePoint, TimePointError> {
+        let zone = self.zone.ok_or(TimePointError::MissingZone)?;
+        return TimePoint::new_in_zone_with(year, month, day, hour, minute, second, zone, policy);
+    }
+
+    // e.g. "2025-10-01 14:05", see TimePoint::parse_local
+    pub fn parse_local_time(&self, text: &str) -> Result<TimePoint, TimePointError> {
+        let zone = self.zone.ok_or(TimePointError::MissingZone)?;
+        return TimePoint::parse_local(text, zone);
+    }
+
+    // offset of this airport's clocks at that instant, or where a date, month or year starts;
+    // None without a zone or outside the zone data
+    pub fn utc_offset_at(&self, time: &TimePoint) -> Option<i32> {
+        let zone = self.zone?;
+        let mut local = TimePoint { precision: Precision::Second, ..time.clone() };
+        local.to_zone(zone).ok()?;
+        return Some(local.utc_offset);
+    }
+}
+
+// calendar date as a day count, validity compares whole days
+pub(crate) fn day_number(date: &TimePoint) -> i64 {
+    return TimePoint::days_from_epoch(date.year, date.month, date.day);
 }
 
+#[derive(PartialEq, Debug, Clone)]
+pub enum AirportError {
+    InvalidIataCode(String),
+    InvalidIcaoCode(String),
+    IcaoRegionMismatch { icao_code: String, country: Country, region_country: Country },
+    InvalidLatitude(f64),
+    InvalidLongitude(f64),
+    ElevationOutOfRange(i32), // unit: ft
+    InvalidValidity { valid_from: String, valid_until: String }
+}
+
+impl fmt::Display for AirportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            AirportError::InvalidIataCode(code) => write!(f, "\"{}\" is not a 3-letter IATA airport code", code),
+            AirportError::InvalidIcaoCode(code) => write!(f, "\"{}\" is not a 4-letter ICAO airport code", code),
+            AirportError::IcaoRegionMismatch { icao_code, country, region_country } =>
+                write!(f, "ICAO code {} belongs to {}, not to {}", icao_code, region_country.get_alpha2(), country.get_alpha2()),
+            AirportError::InvalidLatitude(latitude) => write!(f, "latitude {} is outside -90..90 degrees", latitude),
+            AirportError::InvalidLongitude(longitude) => write!(f, "longitude {} is outside -180..180 degrees", longitude),
+            AirportError::ElevationOutOfRange(elevation) => write!(f, "elevation of {} ft is not plausible for an airport", elevation),
+            AirportError::InvalidValidity { valid_from, valid_until } => write!(f, "airport cannot close on {} before opening on {}", valid_until, valid_from),
+        };
+    }
+}
+
+impl std::error::Error for AirportError {}
+
 #[cfg(test)]
 mod test_airport {
     use super::*;
 
-    #[test]
-    fn test_if_airport_creation_works() {
-        let airport = Airport::new(
+    fn get_test_use() -> Airport {
+        return Airport::new(
             "JFK".into(),
             "KJFK".into(),
             "John F. Kennedy International Airport".into(),
             "New York".into(),
             "NY".into(),
-            "USA".into(),
-        );
+            Country::parse("USA").unwrap(),
+            40.6398,
+            -73.7789,
+            13,
+        ).unwrap();
+    }
+
+    #[test]
+    fn test_if_airport_creation_works() {
+        let airport = get_test_use();
 
         assert_eq!(airport.get_iata_code(), "JFK");
         assert_eq!(airport.get_icao_code(), "KJFK");
         assert_eq!(airport.get_name(), "John F. Kennedy International Airport");
         assert_eq!(airport.get_city(), "New York");
         assert_eq!(airport.get_province(), "NY");
-        assert_eq!(airport.get_country(), "USA");
+        assert_eq!(airport.get_country().get_alpha2(), "US");
+        assert_eq!(airport.get_country().get_name(), "United States");
+        assert_eq!(airport.get_coordinates(), Coordinates { latitude: 40.6398, longitude: -73.7789 });
+        assert_eq!(airport.get_elevation(), 13);
+        assert_eq!(airport.get_zone(), None);
+        assert_eq!(airport.with_zone(chrono_tz::America::New_York).get_zone(), Some(chrono_tz::America::New_York));
     }
 
     #[test]
     fn test_get_code() {
-        let airport = Airport::new(
-            "JFK".into(),
-            "KJFK".into(),
-            "John F. Kennedy International Airport".into(),
-            "New York".into(),
-            "NY".into(),
-            "USA".into(),
-        );
+        assert_eq!(get_test_use().get_code(), "JFK/KJFK");
+    }
+
+    #[test]
+    fn test_elevation_m() {
+        assert!((get_test_use().get_elevation_m() - 3.9624).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_terminals() {
+        assert!(get_test_use().has_terminal("T4"));
+        let jfk = get_test_use().with_terminals(vec!["T1".into(), "T4".into(), "T5".into(), "T7".into(), "T8".into()]);
+        assert!(jfk.has_terminal("t4"));
+        assert!(!jfk.has_terminal("T2"));
+        assert_eq!(jfk.get_terminals().len(), 5);
+    }
+
+    #[test]
+    fn test_validity() {
+        let date = |year: i32, month: i32, day: i32| TimePoint::new_date(year, month, day, 8 * 60).unwrap();
+        let kai_tak = Airport::new("HKG".into(), "VHHX".into(), "Kai Tak Airport".into(), "Hong Kong".into(), "".into(), Country::parse("HK").unwrap(), 22.3287, 114.194, 15)
+            .unwrap()
+            .with_validity(Some(date(1925, 1, 25)), Some(date(1998, 7, 6)))
+            .unwrap()
+            .with_successors(vec![IataAirportCode::new("HKG").unwrap()]);
+        assert!(kai_tak.is_closed());
+        assert!(kai_tak.is_in_service_on(&TimePoint::new(1998, 7, 5, 23, 38, 0, 8 * 60).unwrap()));
+        assert!(!kai_tak.is_in_service_on(&date(1998, 7, 6)));
+        assert!(!kai_tak.is_in_service_on(&TimePoint::new_year(1920, 8 * 60).unwrap()));
+        assert_eq!(kai_tak.get_successors()[0].as_str(), "HKG");
+
+        let chek_lap_kok = Airport::new("HKG".into(), "VHHH".into(), "Hong Kong International Airport".into(), "Hong Kong".into(), "".into(), Country::parse("HK").unwrap(), 22.3089, 113.915, 28)
+            .unwrap()
+            .with_validity(Some(date(1998, 7, 6)), None)
+            .unwrap();
+        assert!(!chek_lap_kok.is_closed());
+        assert!(chek_lap_kok.is_in_service_on(&date(2025, 10, 1)));
+        assert!(!kai_tak.overlaps_in_service(&chek_lap_kok));
+        assert!(get_test_use().overlaps_in_service(&chek_lap_kok));
+
+        assert_eq!(get_test_use().with_validity(Some(date(2000, 1, 1)), Some(date(2000, 1, 1))).unwrap_err(),
+            AirportError::InvalidValidity { valid_from: "2000-01-01".into(), valid_until: "2000-01-01".into() });
+    }
+
+    #[test]
+    fn test_local_time() {
+        let jfk = get_test_use().with_zone(chrono_tz::America::New_York);
+        let summer = jfk.local_time(2025, 7, 1, 18, 30, 0).unwrap();
+        assert_eq!(summer.utc_offset, -4 * 60);
+        assert_eq!(summer.zone, Some(chrono_tz::America::New_York));
+        assert_eq!(jfk.parse_local_time("2025-12-01 18:30").unwrap().utc_offset, -5 * 60);
+        assert_eq!(jfk.local_time(2025, 3, 9, 2, 30, 0), Err(TimePointError::NonExistentLocalTime { zone: chrono_tz::America::New_York }));
+        assert_eq!(jfk.local_time_with(2025, 11, 2, 1, 30, 0, DstPolicy::Later).unwrap().utc_offset, -5 * 60);
+
+        let beijing_time = TimePoint::new(2025, 7, 2, 6, 30, 0, 8 * 60).unwrap();
+        assert_eq!(jfk.utc_offset_at(&beijing_time), Some(-4 * 60));
+        assert_eq!(jfk.utc_offset_at(&TimePoint::new_date(2025, 12, 1, 8 * 60).unwrap()), Some(-5 * 60));
+        assert_eq!(get_test_use().local_time(2025, 7, 1, 18, 30, 0), Err(TimePointError::MissingZone));
+        assert_eq!(get_test_use().utc_offset_at(&beijing_time), None);
+    }
+
+    #[test]
+    fn test_codes_are_checked() {
+        let new_with = |iata_code: &str, icao_code: &str, country: &str| Airport::new(
+            iata_code.into(), icao_code.into(), "John F. Kennedy International Airport".into(),
+            "New York".into(), "NY".into(), Country::parse(country).unwrap(), 40.6398, -73.7789, 13);
+
+        assert_eq!(new_with("jfk", "kjfk", "US").unwrap().get_code(), "JFK/KJFK");
+        assert_eq!(new_with("JF", "KJFK", "US").unwrap_err(), AirportError::InvalidIataCode("JF".into()));
+        assert_eq!(new_with("JFK", "KJFKX", "US").unwrap_err(), AirportError::InvalidIcaoCode("KJFKX".into()));
+        assert_eq!(new_with("JFK", "KJFK", "CN").unwrap_err(),
+            AirportError::IcaoRegionMismatch { icao_code: "KJFK".into(), country: Country::parse("CN").unwrap(), region_country: Country::parse("US").unwrap() });
+        assert_eq!(new_with("JFK", "KJFK", "CN").unwrap_err().to_string(), "ICAO code KJFK belongs to US, not to CN");
+        assert_eq!(new_with("JER", "EGJJ", "JE").unwrap().get_country().get_alpha2(), "JE");
+        assert_eq!(new_with("GCI", "EGJB", "GG").unwrap().get_code(), "GCI/EGJB");
+        assert_eq!(new_with("IOM", "EGNS", "IM").unwrap().get_code(), "IOM/EGNS");
+        assert!(new_with("LHR", "EGLL", "IE").is_err());
+    }
+
+    #[test]
+    fn test_invalid_location_is_rejected() {
+        let new_at = |latitude: f64, longitude: f64, elevation: i32| Airport::new(
+            "PEK".into(), "ZBAA".into(), "Beijing Capital International Airport".into(),
+            "Beijing".into(), "Beijing".into(), Country::parse("China").unwrap(), latitude, longitude, elevation);
 
-        assert_eq!(airport.get_code(), "JFK/KJFK");
+        assert!(new_at(40.0801, 116.5846, 116).is_ok());
+        assert_eq!(new_at(91.0, 116.5846, 116).unwrap_err(), AirportError::InvalidLatitude(91.0));
+        assert_eq!(new_at(40.0801, -180.5, 116).unwrap_err(), AirportError::InvalidLongitude(-180.5));
+        assert!(matches!(new_at(f64::NAN, 116.5846, 116).unwrap_err(), AirportError::InvalidLatitude(_)));
+        assert_eq!(new_at(40.0801, 116.5846, 30000).unwrap_err(), AirportError::ElevationOutOfRange(30000));
     }
 }
@@ -0,0 +1,104 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::domain::airport::AirportError;
+use crate::domain::country::Country;
+
+// 3-letter IATA location identifier, e.g. "JFK", kept in upper case
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone)]
+pub struct IataAirportCode(String);
+
+// 4-letter ICAO location indicator, e.g. "KJFK", kept in upper case
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone)]
+pub struct IcaoAirportCode(String);
+
+impl IataAirportCode {
+    pub fn new(code: &str) -> Result<Self, AirportError> {
+        let code = code.trim();
+        if code.len() != 3 || !code.chars().all(|c| c.is_ascii_alphabetic()) {
+            return Err(AirportError::InvalidIataCode(code.to_string()));
+        }
+        return Ok(IataAirportCode(code.to_ascii_uppercase()));
+    }
+
+    pub fn as_str(&self) -> &str {
+        return &self.0;
+    }
+}
+
+impl IcaoAirportCode {
+    pub fn new(code: &str) -> Result<Self, AirportError> {
+        let code = code.trim();
+        if code.len() != 4 || !code.chars().all(|c| c.is_ascii_alphabetic()) {
+            return Err(AirportError::InvalidIcaoCode(code.to_string()));
+        }
+        return Ok(IcaoAirportCode(code.to_ascii_uppercase()));
+    }
+
+    pub fn as_str(&self) -> &str {
+        return &self.0;
+    }
+
+    // the country the first letters are allocated to, None when the prefix is not in the table
+    pub fn region_country(&self) -> Option<Country> {
+        return Country::from_icao_airport_code(&self.0);
+    }
+}
+
+impl FromStr for IataAirportCode {
+    type Err = AirportError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        return IataAirportCode::new(s);
+    }
+}
+
+impl FromStr for IcaoAirportCode {
+    type Err = AirportError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        return IcaoAirportCode::new(s);
+    }
+}
+
+impl fmt::Display for IataAirportCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "{}", self.0);
+    }
+}
+
+impl fmt::Display for IcaoAirportCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "{}", self.0);
+    }
+}
+
+#[cfg(test)]
+mod test_airport_code {
+    use super::*;
+
+    #[test]
+    fn test_format_and_case() {
+        assert_eq!(IataAirportCode::new(" jfk ").unwrap().as_str(), "JFK");
+        assert_eq!("kjfk".parse::<IcaoAirportCode>().unwrap().to_string(), "KJFK");
+        assert_eq!(IataAirportCode::new("JF"), Err(AirportError::InvalidIataCode("JF".into())));
+        assert_eq!(IataAirportCode::new("J1K"), Err(AirportError::InvalidIataCode("J1K".into())));
+        assert_eq!(IcaoAirportCode::new("KJFKX"), Err(AirportError::InvalidIcaoCode("KJFKX".into())));
+        assert_eq!(IcaoAirportCode::new("K00A"), Err(AirportError::InvalidIcaoCode("K00A".into())));
+    }
+
+    #[test]
+    fn test_region_country() {
+        let region = |code: &str| IcaoAirportCode::new(code).unwrap().region_country().map(|country| country.get_alpha2());
+        assert_eq!(region("KJFK"), Some("US"));
+        assert_eq!(region("PHNL"), Some("US"));
+        assert_eq!(region("ZBAA"), Some("CN"));
+        assert_eq!(region("ZKPY"), Some("KP"));
+        assert_eq!(region("VHHH"), Some("HK"));
+        assert_eq!(region("WBSB"), Some("BN"));
+        assert_eq!(region("UUEE"), Some("RU"));
+        assert_eq!(region("UKBB"), Some("UA"));
+        assert_eq!(region("AYPY"), Some("PG"));
+        assert_eq!(region("XXXX"), None);
+    }
+}
@@ -0,0 +1,501 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use chrono::{Datelike, NaiveDate};
+use chrono_tz::Tz;
+
+use crate::domain::{airport::{day_number, Airport, AirportError}, airport_code::{IataAirportCode, IcaoAirportCode}, country::Country, time_point::TimePoint};
+
+// a trimmed OurAirports airports.csv plus "time_zone" and "valid_from" columns, see https://ourairports.com/data/
+const BUNDLED_AIRPORTS: &str = include_str!("../../data/airports.csv");
+// closed airports in the same format with "valid_until" and "successors" filled in
+const BUNDLED_HISTORY: &str = include_str!("../../data/airport_history.csv");
+
+pub struct AirportRegistry {
+    airports: Vec<Airport>,
+    keywords: Vec<String>, // alternative names from the data, same index as airports
+    by_iata: HashMap<String, Vec<usize>>, // every airport that used the code, value is index into airports
+    by_icao: HashMap<String, Vec<usize>>,
+    predecessors: Vec<Vec<usize>>, // airports whose successors include this one, same index as airports
+    skipped: Vec<RegistryError> // rows left out while reading, see `from_csv`
+}
+
+impl AirportRegistry {
+    pub fn bundled() -> Self {
+        let mut registry = AirportRegistry::new();
+        registry.read_csv(BUNDLED_AIRPORTS).expect("bundled airports.csv is valid");
+        registry.read_csv(BUNDLED_HISTORY).expect("bundled airport_history.csv is valid");
+        return registry;
+    }
+
+    fn new() -> Self {
+        return AirportRegistry { airports: Vec::new(), keywords: Vec::new(), by_iata: HashMap::new(), by_icao: HashMap::new(), predecessors: Vec::new(), skipped: Vec::new() };
+    }
+
+    // reads the OurAirports airports.csv format; columns are found by header name so a full export or a trimmed one both work,
+    // rows without an IATA or ICAO code are skipped, as are closed airports unless "valid_until" says when they closed,
+    // and a code used by two airports at the same time keeps the first one.
+    // A row with an unknown country or an ICAO code from another country's region is skipped and listed in `get_skipped`;
+    // malformed values still fail the whole file.
+    pub fn from_csv(text: &str) -> Result<Self, RegistryError> {
+        let mut registry = AirportRegistry::new();
+        registry.read_csv(text)?;
+        return Ok(registry);
+    }
+
+    fn read_csv(&mut self, text: &str) -> Result<(), RegistryError> {
+        let mut records = parse_csv(text).into_iter();
+        let header = records.next().ok_or(RegistryError::MissingColumn("ident"))?;
+        let columns = Columns::new(&header)?;
+        for (index, record) in records.enumerate() {
+            let row = index + 2; // 1-based, after the header
+            match columns.read(&record, row) {
+                Ok(Some(airport)) => self.insert(airport, columns.keywords(&record)),
+                Ok(None) => {},
+                Err(error) if error.skips_row() => self.skipped.push(error),
+                Err(error) => return Err(error),
+            }
+        }
+        self.link_predecessors();
+        return Ok(());
+    }
+
+    fn insert(&mut self, airport: Airport, keywords: String) {
+        let iata_code = airport.get_iata_code().to_string();
+        let icao_code = airport.get_icao_code().to_string();
+        let same_code = self.by_iata.get(&iata_code).into_iter().flatten();
+        if same_code.map(|&index| &self.airports[index]).any(|known| known.overlaps_in_service(&airport)) {
+            return;
+        }
+        let index = self.airports.len();
+        self.by_iata.entry(iata_code).or_default().push(index);
+        self.by_icao.entry(icao_code).or_default().push(index);
+        self.airports.push(airport);
+        self.keywords.push(keywords);
+    }
+
+    // successors may point at airports from a later file, so the links are rebuilt once every file is read
+    fn link_predecessors(&mut self) {
+        let mut predecessors = vec![Vec::new(); self.airports.len()];
+        for (index, airport) in self.airports.iter().enumerate() {
+            for successor in self.get_successors(airport) {
+                if let Some(successor) = self.index_of(successor) {
+                    predecessors[successor].push(index);
+                }
+            }
+        }
+        self.predecessors = predecessors;
+    }
+
+    // an IATA code is never used by two airports at once, so the code and the opening day identify an airport,
+    // which also finds the entry for a clone
+    fn index_of(&self, airport: &Airport) -> Option<usize> {
+        return self.by_iata.get(airport.get_iata_code())?.iter().copied().find(|&index| {
+            let known = &self.airports[index];
+            return known.get_icao_code() == airport.get_icao_code() && known.get_valid_from() == airport.get_valid_from();
+        });
+    }
+
+    // the airport that uses the code today, or the one that used it last
+    fn latest(&self, indices: Option<&Vec<usize>>) -> Option<&Airport> {
+        return indices?.iter()
+            .map(|&index| &self.airports[index])
+            .max_by_key(|airport| airport.get_valid_until().map_or(i64::MAX, day_number));
+    }
+
+    fn in_service_on<'a>(&'a self, indices: Option<&Vec<usize>>, date: &TimePoint) -> Option<&'a Airport> {
+        return indices?.iter().map(|&index| &self.airports[index]).find(|airport| airport.is_in_service_on(date));
+    }
+
+    pub fn get_by_iata(&self, code: &str) -> Option<&Airport> {
+        return self.latest(self.by_iata.get(&code.trim().to_uppercase()));
+    }
+
+    pub fn get_by_icao(&self, code: &str) -> Option<&Airport> {
+        return self.latest(self.by_icao.get(&code.trim().to_uppercase()));
+    }
+
+    // 3 letters are taken as IATA, 4 as ICAO
+    pub fn get(&self, code: &str) -> Option<&Airport> {
+        return match code.trim().len() {
+            3 => self.get_by_iata(code),
+            4 => self.get_by_icao(code),
+            _ => None,
+        };
+    }
+
+    // the airport a code meant on a flight date, e.g. HKG is Kai Tak in 1997
+    pub fn get_by_iata_at(&self, code: &str, date: &TimePoint) -> Option<&Airport> {
+        return self.in_service_on(self.by_iata.get(&code.trim().to_uppercase()), date);
+    }
+
+    pub fn get_by_icao_at(&self, code: &str, date: &TimePoint) -> Option<&Airport> {
+        return self.in_service_on(self.by_icao.get(&code.trim().to_uppercase()), date);
+    }
+
+    pub fn get_at(&self, code: &str, date: &TimePoint) -> Option<&Airport> {
+        return match code.trim().len() {
+            3 => self.get_by_iata_at(code, date),
+            4 => self.get_by_icao_at(code, date),
+            _ => None,
+        };
+    }
+
+    // successor codes are resolved on the closing day, or the next opening after it when there was a gap
+    // (SXF closed a week before BER opened), so HKG after Kai Tak is Chek Lap Kok
+    pub fn get_successors(&self, airport: &Airport) -> Vec<&Airport> {
+        return airport.get_successors().iter()
+            .filter_map(|code| {
+                let Some(until) = airport.get_valid_until() else {
+                    return self.get_by_iata(code.as_str());
+                };
+                let closing_day = day_number(until);
+                return self.get_by_iata_at(code.as_str(), until).or_else(|| self.by_iata.get(code.as_str())?.iter()
+                    .map(|&index| &self.airports[index])
+                    .filter_map(|candidate| {
+                        let opening_day = day_number(candidate.get_valid_from()?);
+                        return (opening_day >= closing_day).then_some((opening_day, candidate));
+                    })
+                    .min_by_key(|(opening_day, _)| *opening_day)
+                    .map(|(_, candidate)| candidate));
+            })
+            .collect();
+    }
+
+    pub fn get_predecessors(&self, airport: &Airport) -> Vec<&Airport> {
+        let Some(index) = self.index_of(airport) else {
+            return Vec::new();
+        };
+        return self.predecessors[index].iter().map(|&predecessor| &self.airports[predecessor]).collect();
+    }
+
+    // follows successors to the airport serving the place today, e.g. TXL -> BER
+    pub fn get_current<'a>(&'a self, airport: &'a Airport) -> &'a Airport {
+        let mut current = airport;
+        for _ in 0..self.airports.len() {
+            match self.get_successors(current).first() {
+                Some(successor) => current = successor,
+                None => break,
+            }
+        }
+        return current;
+    }
+
+    // visits per airport keyed by "IATA/ICAO"; merging history counts Kai Tak as HKG/VHHH and TXL as BER/EDDB
+    pub fn airport_visits<'a, I: IntoIterator<Item = &'a Airport>>(&'a self, airports: I, merge_history: bool) -> BTreeMap<String, usize> {
+        let mut visits = BTreeMap::new();
+        for airport in airports {
+            let airport = if merge_history { self.get_current(airport) } else { airport };
+            *visits.entry(airport.get_code()).or_insert(0) += 1;
+        }
+        return visits;
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Airport> {
+        return self.airports.iter();
+    }
+
+    // e.g. "LON, Heathrow" for LHR, empty when the data has none
+    pub fn get_keywords(&self, airport: &Airport) -> &str {
+        return self.index_of(airport).map_or("", |index| self.keywords[index].as_str());
+    }
+
+    // rows that were read but left out, with the reason
+    pub fn get_skipped(&self) -> &[RegistryError] {
+        return &self.skipped;
+    }
+
+    pub fn len(&self) -> usize {
+        return self.airports.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.airports.is_empty();
+    }
+}
+
+// positions of the columns we read, optional ones may be missing from the header
+struct Columns {
+    ident: usize,
+    airport_type: Option<usize>,
+    name: usize,
+    latitude: usize,
+    longitude: usize,
+    elevation: Option<usize>,
+    iso_country: usize,
+    iso_region: Option<usize>,
+    municipality: Option<usize>,
+    icao_code: Option<usize>,
+    gps_code: Option<usize>,
+    iata_code: usize,
+    keywords: Option<usize>,
+    time_zone: Option<usize>,
+    valid_from: Option<usize>,
+    valid_until: Option<usize>,
+    successors: Option<usize>
+}
+
+impl Columns {
+    fn new(header: &[String]) -> Result<Self, RegistryError> {
+        let find = |column: &'static str| header.iter().position(|name| name.trim() == column);
+        let require = |column: &'static str| find(column).ok_or(RegistryError::MissingColumn(column));
+        return Ok(Columns {
+            ident: require("ident")?,
+            airport_type: find("type"),
+            name: require("name")?,
+            latitude: require("latitude_deg")?,
+            longitude: require("longitude_deg")?,
+            elevation: find("elevation_ft"),
+            iso_country: require("iso_country")?,
+            iso_region: find("iso_region"),
+            municipality: find("municipality"),
+            icao_code: find("icao_code"),
+            gps_code: find("gps_code"),
+            iata_code: require("iata_code")?,
+            keywords: find("keywords"),
+            time_zone: find("time_zone"),
+            valid_from: find("valid_from"),
+            valid_until: find("valid_until"),
+            successors: find("successors")
+        });
+    }
+
+    fn keywords(&self, record: &[String]) -> String {
+        return self.keywords.and_then(|index| record.get(index)).map(|value| value.trim().to_string()).unwrap_or_default();
+    }
+
+    fn read(&self, record: &[String], row: usize) -> Result<Option<Airport>, RegistryError> {
+        let field = |index: Option<usize>| index.and_then(|index| record.get(index)).map(|value| value.trim()).unwrap_or("");
+        let iata_code = field(Some(self.iata_code));
+        if iata_code.is_empty() || (field(self.airport_type) == "closed" && field(self.valid_until).is_empty()) {
+            return Ok(None);
+        }
+        // older exports have no icao_code column, gps_code then ident carry the ICAO code there
+        let icao_code = [field(self.icao_code), field(self.gps_code), field(Some(self.ident))]
+            .into_iter()
+            .find(|code| IcaoAirportCode::new(code).is_ok());
+        let Some(icao_code) = icao_code else {
+            return Ok(None);
+        };
+        let iso_country = field(Some(self.iso_country));
+        let country = Country::from_alpha2(iso_country)
+            .ok_or_else(|| RegistryError::InvalidField { row, column: "iso_country", value: iso_country.to_string() })?;
+        let region = field(self.iso_region);
+        let province = region.strip_prefix(iso_country).and_then(|rest| rest.strip_prefix('-')).unwrap_or(region);
+
+        let number = |column: &'static str, value: &str| value.parse::<f64>()
+            .map_err(|_| RegistryError::InvalidField { row, column, value: value.to_string() });
+        let latitude = number("latitude_deg", field(Some(self.latitude)))?;
+        let longitude = number("longitude_deg", field(Some(self.longitude)))?;
+        let elevation = match field(self.elevation) {
+            "" => 0,
+            value => value.parse::<i32>().map_err(|_| RegistryError::InvalidField { row, column: "elevation_ft", value: value.to_string() })?,
+        };
+
+        let airport = Airport::new(
+            iata_code.to_string(),
+            icao_code.to_string(),
+            field(Some(self.name)).to_string(),
+            field(self.municipality).to_string(),
+            province.to_string(),
+            country,
+            latitude,
+            longitude,
+            elevation,
+        ).map_err(|error| RegistryError::InvalidAirport { row, error })?;
+        let airport = match field(self.time_zone) {
+            "" => airport,
+            value => airport.with_zone(value.parse::<Tz>().map_err(|_| RegistryError::InvalidField { row, column: "time_zone", value: value.to_string() })?),
+        };
+
+        let date = |column: &'static str, value: &str| -> Result<Option<TimePoint>, RegistryError> {
+            if value.is_empty() {
+                return Ok(None);
+            }
+            let invalid = || RegistryError::InvalidField { row, column, value: value.to_string() };
+            let date = NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| invalid())?;
+            return TimePoint::new_date(date.year(), date.month() as i32, date.day() as i32, 0).map(Some).map_err(|_| invalid());
+        };
+        let valid_from = date("valid_from", field(self.valid_from))?;
+        let valid_until = date("valid_until", field(self.valid_until))?;
+        let successors = field(self.successors)
+            .split([',', ' '])
+            .filter(|code| !code.is_empty())
+            .map(IataAirportCode::new)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|error| RegistryError::InvalidAirport { row, error })?;
+        let airport = airport.with_validity(valid_from, valid_until)
+            .map_err(|error| RegistryError::InvalidAirport { row, error })?
+            .with_successors(successors);
+        return Ok(Some(airport));
+    }
+}
+
+// RFC 4180 records: quoted fields may hold commas, doubled quotes and line breaks
+fn parse_csv(text: &str) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, in_quotes) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            },
+            ('"', true) => in_quotes = false,
+            ('"', false) => in_quotes = true,
+            (',', false) => record.push(std::mem::take(&mut field)),
+            ('\r', false) => {},
+            ('\n', false) => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            },
+            _ => field.push(c),
+        }
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    return records.into_iter().filter(|record| record.iter().any(|field| !field.is_empty())).collect();
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum RegistryError {
+    MissingColumn(&'static str),
+    InvalidField { row: usize, column: &'static str, value: String },
+    InvalidAirport { row: usize, error: AirportError }
+}
+
+impl RegistryError {
+    fn skips_row(&self) -> bool {
+        return matches!(self, RegistryError::InvalidAirport { error: AirportError::IcaoRegionMismatch { .. }, .. }
+            | RegistryError::InvalidField { column: "iso_country", .. });
+    }
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            RegistryError::MissingColumn(column) => write!(f, "airport data has no \"{}\" column", column),
+            RegistryError::InvalidField { row, column, value } => write!(f, "row {}: \"{}\" is not a valid {}", row, value, column),
+            RegistryError::InvalidAirport { row, error } => write!(f, "row {}: {}", row, error),
+        };
+    }
+}
+
+impl std::error::Error for RegistryError {}
+
+#[cfg(test)]
+mod test_airport_registry {
+    use super::*;
+
+    #[test]
+    fn test_bundled_lookup() {
+        let registry = AirportRegistry::bundled();
+        let pek = registry.get_by_iata("PEK").unwrap();
+        assert_eq!(pek.get_icao_code(), "ZBAA");
+        assert_eq!(pek.get_name(), "Beijing Capital International Airport");
+        assert_eq!(pek.get_city(), "Beijing");
+        assert_eq!(pek.get_country().get_name(), "China");
+        assert_eq!(pek.get_zone(), Some(chrono_tz::Asia::Shanghai));
+        assert_eq!(registry.get_by_icao("egll").unwrap().get_iata_code(), "LHR");
+        assert_eq!(registry.get("jfk").unwrap().get_province(), "NY");
+        assert_eq!(registry.get("KJFK").unwrap().get_iata_code(), "JFK");
+        assert!(registry.get("XXX").is_none());
+        assert_eq!(registry.iter().count(), registry.len());
+        assert_eq!(registry.get_keywords(registry.get("LHR").unwrap()), "LON, Heathrow");
+        assert_eq!(registry.get_keywords(&registry.get("LHR").unwrap().clone()), "LON, Heathrow");
+        assert!(registry.get_skipped().is_empty());
+    }
+
+    #[test]
+    fn test_full_ourairports_export() {
+        let text = "\"id\",\"ident\",\"type\",\"name\",\"latitude_deg\",\"longitude_deg\",\"elevation_ft\",\"continent\",\"iso_country\",\"iso_region\",\"municipality\",\"scheduled_service\",\"gps_code\",\"iata_code\",\"local_code\",\"home_link\",\"wikipedia_link\",\"keywords\"\r\n\
+            3632,\"KLAX\",\"large_airport\",\"Los Angeles International Airport\",33.942501,-118.407997,125,\"NA\",\"US\",\"US-CA\",\"Los Angeles\",\"yes\",\"KLAX\",\"LAX\",\"LAX\",\"https://www.flylax.com/\",\"\",\"\"\r\n\
+            6523,\"00A\",\"heliport\",\"Total RF Heliport\",40.070985,-74.933689,11,\"NA\",\"US\",\"US-PA\",\"Bensalem\",\"no\",\"K00A\",\"\",\"00A\",\"\",\"\",\"\"\r\n\
+            2,\"VHHX\",\"closed\",\"Kai Tak Airport\",22.328699,114.194,15,\"AS\",\"HK\",\"HK-U-A\",\"Hong Kong\",\"no\",\"\",\"HKG\",\"\",\"\",\"\",\"\"\r\n\
+            4,\"EGLC\",\"medium_airport\",\"London City Airport\",51.505299,0.055278,19,\"EU\",\"GB\",\"GB-ENG\",\"London\",\"yes\",\"EGLC\",\"LCY\",\"\",\"\",\"\",\"\"\"Docklands\"\", LON\"\r\n";
+        let registry = AirportRegistry::from_csv(text).unwrap();
+        assert_eq!(registry.len(), 2);
+        let lax = registry.get_by_iata("LAX").unwrap();
+        assert_eq!(lax.get_icao_code(), "KLAX");
+        assert_eq!(lax.get_elevation(), 125);
+        assert_eq!(lax.get_zone(), None);
+        assert!(registry.get_by_iata("HKG").is_none());
+        assert_eq!(registry.get_keywords(registry.get_by_icao("EGLC").unwrap()), "\"Docklands\", LON");
+    }
+
+    #[test]
+    fn test_history() {
+        let registry = AirportRegistry::bundled();
+        let date = |year: i32, month: i32, day: i32| TimePoint::new_date(year, month, day, 0).unwrap();
+
+        let kai_tak = registry.get_by_iata_at("HKG", &date(1997, 7, 1)).unwrap();
+        assert_eq!(kai_tak.get_icao_code(), "VHHX");
+        assert_eq!(registry.get_by_iata_at("HKG", &date(1998, 7, 6)).unwrap().get_icao_code(), "VHHH");
+        assert_eq!(registry.get("HKG").unwrap().get_icao_code(), "VHHH");
+        assert_eq!(registry.get_by_icao("VHHX").unwrap().get_name(), "Kai Tak Airport");
+        assert_eq!(registry.get_successors(kai_tak)[0].get_icao_code(), "VHHH");
+        assert_eq!(registry.get_predecessors(registry.get("HKG").unwrap())[0].get_icao_code(), "VHHX");
+
+        // SXF handed EDDB over to BER
+        assert_eq!(registry.get_at("EDDB", &date(2015, 6, 1)).unwrap().get_iata_code(), "SXF");
+        assert_eq!(registry.get("EDDB").unwrap().get_iata_code(), "BER");
+        assert!(registry.get_at("BER", &date(2015, 6, 1)).is_none());
+        let ber = registry.get("BER").unwrap();
+        let mut predecessors: Vec<&str> = registry.get_predecessors(ber).iter().map(|airport| airport.get_iata_code()).collect();
+        predecessors.sort();
+        assert_eq!(predecessors, vec!["SXF", "TXL"]);
+        assert_eq!(registry.get_predecessors(&ber.clone()).len(), 2);
+        assert!(registry.get_predecessors(registry.get("TXL").unwrap()).is_empty());
+        assert_eq!(registry.get_current(registry.get("TXL").unwrap()).get_code(), "BER/EDDB");
+        assert_eq!(registry.get_current(ber).get_code(), "BER/EDDB");
+    }
+
+    #[test]
+    fn test_airport_visits_merge_history() {
+        let registry = AirportRegistry::bundled();
+        let date = |year: i32, month: i32, day: i32| TimePoint::new_date(year, month, day, 0).unwrap();
+        let visits = [("HKG", date(1996, 3, 1)), ("HKG", date(2019, 3, 1)), ("TXL", date(2012, 5, 1)), ("SXF", date(2016, 5, 1)), ("BER", date(2023, 5, 1))]
+            .map(|(code, date)| registry.get_by_iata_at(code, &date).unwrap());
+
+        let separate = registry.airport_visits(visits, false);
+        assert_eq!(separate.len(), 5);
+        assert_eq!(separate["HKG/VHHX"], 1);
+        let merged = registry.airport_visits(visits, true);
+        assert_eq!(merged.into_iter().collect::<Vec<_>>(), vec![("BER/EDDB".to_string(), 3), ("HKG/VHHH".to_string(), 2)]);
+    }
+
+    #[test]
+    fn test_region_mismatch_skips_row() {
+        let header = "ident,name,latitude_deg,longitude_deg,iso_country,iata_code\n";
+        let text = format!("{}UAFM,Manas,43.06,74.47,KG,FRU\nZBAA,Capital,40.08,116.58,US,PEK\nUTDD,Dushanbe,38.54,68.82,TJ,DYU\n", header);
+        let registry = AirportRegistry::from_csv(&text).unwrap();
+        assert_eq!(registry.iter().map(|airport| airport.get_iata_code()).collect::<Vec<_>>(), vec!["FRU", "DYU"]);
+        assert_eq!(registry.get_skipped(), [RegistryError::InvalidAirport { row: 3, error: AirportError::IcaoRegionMismatch {
+            icao_code: "ZBAA".into(), country: Country::from_alpha2("US").unwrap(), region_country: Country::from_alpha2("CN").unwrap() } }]);
+    }
+
+    #[test]
+    fn test_invalid_data() {
+        assert_eq!(AirportRegistry::from_csv("ident,name\n").err(), Some(RegistryError::MissingColumn("latitude_deg")));
+        let header = "ident,name,latitude_deg,longitude_deg,iso_country,iata_code,time_zone\n";
+        assert_eq!(AirportRegistry::from_csv(&format!("{}ZBAA,Capital,north,116.5,CN,PEK,\n", header)).err(),
+            Some(RegistryError::InvalidField { row: 2, column: "latitude_deg", value: "north".into() }));
+        assert_eq!(AirportRegistry::from_csv(&format!("{}ZBAA,Capital,40.1,196.5,CN,PEK,\n", header)).err(),
+            Some(RegistryError::InvalidAirport { row: 2, error: AirportError::InvalidLongitude(196.5) }));
+        assert_eq!(AirportRegistry::from_csv(&format!("{}ZBAA,Capital,40.1,116.5,CN,PEK,Asia/Peking\n", header)).err(),
+            Some(RegistryError::InvalidField { row: 2, column: "time_zone", value: "Asia/Peking".into() }));
+        let registry = AirportRegistry::from_csv(&format!("{}ZBAA,Capital,40.1,116.5,China,PEK,\nEGJJ,Jersey,49.21,-2.2,JE,JER,\n", header)).unwrap();
+        assert_eq!(registry.iter().map(|airport| airport.get_iata_code()).collect::<Vec<_>>(), vec!["JER"]);
+        assert_eq!(registry.get_skipped(), [RegistryError::InvalidField { row: 2, column: "iso_country", value: "China".into() }]);
+        let header = "ident,name,latitude_deg,longitude_deg,iso_country,iata_code,valid_until,successors\n";
+        assert_eq!(AirportRegistry::from_csv(&format!("{}ZBNY,Nanyuan,39.78,116.39,CN,NAY,2019-09-31,PKX\n", header)).err(),
+            Some(RegistryError::InvalidField { row: 2, column: "valid_until", value: "2019-09-31".into() }));
+        assert_eq!(AirportRegistry::from_csv(&format!("{}ZBNY,Nanyuan,39.78,116.39,CN,NAY,2019-09-25,PK\n", header)).err(),
+            Some(RegistryError::InvalidAirport { row: 2, error: AirportError::InvalidIataCode("PK".into()) }));
+    }
+}
@@ -0,0 +1,222 @@
+// ranked, typo-tolerant search over the airport registry, e.g. "shanghai", "heathrow", "frankfur"
+
+use crate::domain::{airport::Airport, airport_registry::AirportRegistry};
+
+pub struct AirportMatch<'a> {
+    pub airport: &'a Airport,
+    pub score: u32 // higher is better
+}
+
+impl AirportMatch<'_> {
+    // names the airport next to the city so multi-airport cities can be told apart, e.g. "Shanghai (PVG) - Shanghai Pudong International Airport, China";
+    // a closed airport ends with its closing date, e.g. "..., Hong Kong, closed 1998-07-06"
+    pub fn label(&self) -> String {
+        let label = format!("{} ({}) - {}, {}", self.airport.get_city(), self.airport.get_iata_code(), self.airport.get_name(), self.airport.get_country());
+        return match self.airport.get_valid_until() {
+            Some(until) => format!("{}, closed {}", label, until),
+            None => label,
+        };
+    }
+}
+
+// the searchable text of one airport, normalized once per search rather than once per query word
+struct SearchFields {
+    city: String,
+    name: String,
+    keywords: String,
+    country_name: String
+}
+
+impl SearchFields {
+    fn of(airport: &Airport, keywords: &str) -> Self {
+        return SearchFields {
+            city: normalize(airport.get_city()),
+            name: normalize(airport.get_name()),
+            keywords: normalize(keywords),
+            country_name: normalize(airport.get_country().get_name())
+        };
+    }
+}
+
+// every word of the query has to match a code, the city, the name, the keywords or the country of an airport
+pub fn search<'a>(registry: &'a AirportRegistry, query: &str, limit: usize) -> Vec<AirportMatch<'a>> {
+    let words: Vec<String> = normalize(query).split_whitespace().map(|word| word.to_string()).collect();
+    if words.is_empty() {
+        return Vec::new();
+    }
+    let mut matches: Vec<AirportMatch> = registry.iter()
+        .filter_map(|airport| {
+            let fields = SearchFields::of(airport, registry.get_keywords(airport));
+            let score = words.iter().map(|word| score_word(airport, &fields, word)).sum::<Option<u32>>()?;
+            return Some(AirportMatch { airport, score });
+        })
+        .collect();
+    matches.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.airport.get_iata_code().cmp(b.airport.get_iata_code())));
+    matches.truncate(limit);
+    return matches;
+}
+
+// one airport for an exact code, otherwise the best candidates to choose from
+pub fn resolve<'a>(registry: &'a AirportRegistry, query: &str, limit: usize) -> Result<&'a Airport, Vec<AirportMatch<'a>>> {
+    if let Some(airport) = registry.get(query) {
+        return Ok(airport);
+    }
+    return Err(search(registry, query, limit));
+}
+
+fn score_word(airport: &Airport, fields: &SearchFields, word: &str) -> Option<u32> {
+    let upper = word.to_uppercase();
+    if airport.get_iata_code() == upper {
+        return Some(1000);
+    }
+    if airport.get_icao_code() == upper {
+        return Some(950);
+    }
+    let SearchFields { city, name, keywords, country_name } = fields;
+    if city == word {
+        return Some(800);
+    }
+    if city.split_whitespace().any(|part| part.starts_with(word)) {
+        return Some(700);
+    }
+    if name.split_whitespace().any(|part| part.starts_with(word)) {
+        return Some(650);
+    }
+    if word.len() < 3 && (airport.get_iata_code().starts_with(&upper) || airport.get_icao_code().starts_with(&upper)) {
+        return Some(600);
+    }
+    if keywords.split([',', ' ']).any(|part| part.starts_with(word)) {
+        return Some(550);
+    }
+    if name.contains(word) {
+        return Some(500);
+    }
+    // the country by either code or by a whole word of its name, so "cn", "chn" and "china" all work
+    let country = airport.get_country();
+    if country.get_alpha2().eq_ignore_ascii_case(word) || country.get_alpha3().eq_ignore_ascii_case(word)
+        || country_name.split_whitespace().any(|part| part == word) {
+        return Some(300);
+    }
+    // about one typo per four letters, none for short words where everything is one typo away
+    let allowed = (word.chars().count() / 4) as u32;
+    if allowed == 0 {
+        return None;
+    }
+    let typos = city.split_whitespace()
+        .chain(name.split_whitespace())
+        .chain(keywords.split([',', ' ']))
+        .filter(|part| !part.is_empty())
+        .map(|part| prefix_distance(word, part))
+        .min()?;
+    if typos > allowed {
+        return None;
+    }
+    return Some(400 - 100 * typos);
+}
+
+// edit distance between `query` and the closest prefix of `word`, so a typo in a half-typed word still matches
+fn prefix_distance(query: &str, word: &str) -> u32 {
+    let query: Vec<char> = query.chars().collect();
+    let word: Vec<char> = word.chars().collect();
+    let mut previous: Vec<u32> = (0..=word.len() as u32).collect();
+    for (i, q) in query.iter().enumerate() {
+        let mut current = vec![i as u32 + 1; word.len() + 1];
+        for (j, w) in word.iter().enumerate() {
+            let substitution = previous[j] + if q == w { 0 } else { 1 };
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    return previous.into_iter().min().unwrap_or(0);
+}
+
+// lowercase with accents folded, so "zurich" finds "Zürich" and "SAO PAULO" finds "São Paulo";
+// full Unicode lowercasing, the combining dot that "İ" lowercases to is dropped
+fn normalize(text: &str) -> String {
+    return text.chars()
+        .flat_map(char::to_lowercase)
+        .filter(|c| !('\u{0300}'..='\u{036f}').contains(c))
+        .map(|c| match c {
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' => 'a',
+            'ç' => 'c',
+            'è' | 'é' | 'ê' | 'ë' => 'e',
+            'ì' | 'í' | 'î' | 'ï' => 'i',
+            'ñ' => 'n',
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' => 'o',
+            'ù' | 'ú' | 'û' | 'ü' => 'u',
+            '–' | '-' | '\'' | '.' => ' ',
+            c => c,
+        })
+        .collect();
+}
+
+#[cfg(test)]
+mod test_airport_search {
+    use super::*;
+
+    fn top_codes(registry: &AirportRegistry, query: &str, limit: usize) -> Vec<String> {
+        return search(registry, query, limit).iter().map(|m| m.airport.get_iata_code().to_string()).collect();
+    }
+
+    #[test]
+    fn test_search_by_city_and_name() {
+        let registry = AirportRegistry::bundled();
+        assert_eq!(top_codes(&registry, "shanghai", 5), vec!["PVG", "SHA"]);
+        assert_eq!(top_codes(&registry, "heathrow", 5), vec!["LHR"]);
+        assert_eq!(top_codes(&registry, "frankfur", 1), vec!["FRA"]);
+        assert_eq!(top_codes(&registry, "zurich", 1), vec!["ZRH"]);
+        assert_eq!(top_codes(&registry, "sao paulo", 1), vec!["GRU"]);
+        assert_eq!(top_codes(&registry, "ZÜRICH", 1), vec!["ZRH"]);
+        assert_eq!(top_codes(&registry, "SÃO PAULO", 1), vec!["GRU"]);
+        assert_eq!(top_codes(&registry, "tokyo", 5), vec!["HND", "NRT"]);
+        assert_eq!(top_codes(&registry, "shanghai china", 5), vec!["PVG", "SHA"]);
+        assert_eq!(top_codes(&registry, "shanghai chn", 5), vec!["PVG", "SHA"]);
+    }
+
+    #[test]
+    fn test_search_by_code() {
+        let registry = AirportRegistry::bundled();
+        assert_eq!(top_codes(&registry, "pek", 1), vec!["PEK"]);
+        assert_eq!(top_codes(&registry, "zbaa", 1), vec!["PEK"]);
+        assert_eq!(top_codes(&registry, "eg", 3), vec!["LCY", "LGW", "LHR"]);
+        assert!(resolve(&registry, "KJFK", 5).is_ok());
+        assert_eq!(resolve(&registry, "london", 10).err().unwrap().len(), 6);
+    }
+
+    #[test]
+    fn test_search_tolerates_typos() {
+        let registry = AirportRegistry::bundled();
+        assert_eq!(top_codes(&registry, "heathrw", 1), vec!["LHR"]);
+        assert_eq!(top_codes(&registry, "frankfrut", 1), vec!["FRA"]);
+        assert_eq!(top_codes(&registry, "beijng capital", 1), vec!["PEK"]);
+        assert!(search(&registry, "qqqqqq", 5).is_empty());
+        assert!(search(&registry, "  ", 5).is_empty());
+    }
+
+    #[test]
+    fn test_label_disambiguates() {
+        let registry = AirportRegistry::bundled();
+        let labels: Vec<String> = search(&registry, "shanghai", 5).iter().map(|m| m.label()).collect();
+        assert_eq!(labels, vec![
+            "Shanghai (PVG) - Shanghai Pudong International Airport, China",
+            "Shanghai (SHA) - Shanghai Hongqiao International Airport, China"
+        ]);
+        let kai_tak = search(&registry, "kai tak", 1);
+        assert_eq!(kai_tak[0].label(), "Hong Kong (HKG) - Kai Tak Airport, Hong Kong, closed 1998-07-06");
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("Zürich"), "zurich");
+        assert_eq!(normalize("ZÜRICH"), "zurich");
+        assert_eq!(normalize("İSTANBUL"), "istanbul");
+        assert_eq!(normalize("Xi'an Xianyang"), "xi an xianyang");
+    }
+
+    #[test]
+    fn test_prefix_distance() {
+        assert_eq!(prefix_distance("frankfur", "frankfurt"), 0);
+        assert_eq!(prefix_distance("heathrw", "heathrow"), 1);
+        assert_eq!(prefix_distance("kitten", "sitting"), 2);
+    }
+}
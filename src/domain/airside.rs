@@ -0,0 +1,151 @@
+// where on the airfield a departure or arrival happened: terminal, gate, stand and runway
+
+use std::fmt;
+
+use crate::domain::flight::{Flight, FlightError};
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum Stand {
+    Contact(String), // at the terminal, boarded through a jet bridge
+    Remote(String) // out on the apron, bussed to the terminal
+}
+
+impl Stand {
+    pub fn get_name(&self) -> &str {
+        return match self {
+            Stand::Contact(name) | Stand::Remote(name) => name,
+        };
+    }
+
+    pub fn is_remote(&self) -> bool {
+        return matches!(self, Stand::Remote(_));
+    }
+}
+
+// runway designator: magnetic heading in tens of degrees, 01-36, with L/C/R for parallel runways, e.g. "36R"
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct Runway(String);
+
+impl Runway {
+    pub fn new(designator: &str) -> Result<Self, FlightError> {
+        let designator = designator.trim().to_ascii_uppercase();
+        let digits_end = designator.find(|c: char| !c.is_ascii_digit()).unwrap_or(designator.len());
+        let (heading, side) = designator.split_at(digits_end);
+        let valid_heading = (1..=2).contains(&heading.len()) && heading.parse::<u32>().is_ok_and(|heading| (1..=36).contains(&heading));
+        if !valid_heading || !matches!(side, "" | "L" | "C" | "R") {
+            return Err(FlightError::InvalidRunway(designator));
+        }
+        return Ok(Runway(format!("{:0>2}{}", heading, side)));
+    }
+
+    pub fn as_str(&self) -> &str {
+        return &self.0;
+    }
+}
+
+impl fmt::Display for Runway {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "{}", self.0);
+    }
+}
+
+// each part is None until recorded
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct Airside {
+    pub terminal: Option<String>, // as the airport names it, e.g. "T3"
+    pub gate: Option<String>,
+    pub stand: Option<Stand>,
+    pub runway: Option<Runway>
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub struct StandUsage {
+    pub contact: usize,
+    pub remote: usize
+}
+
+impl StandUsage {
+    // share of stops with a known stand that were bussed, None when no stand was recorded
+    pub fn remote_share(&self) -> Option<f64> {
+        let total = self.contact + self.remote;
+        if total == 0 {
+            return None;
+        }
+        return Some(self.remote as f64 / total as f64);
+    }
+}
+
+// contact and remote stands used at one airport, optionally at one terminal, e.g. how often we got bussed at PEK T3
+pub fn stand_usage<'a, I: IntoIterator<Item = &'a Flight>>(flights: I, iata_code: &str, terminal: Option<&str>) -> StandUsage {
+    let mut usage = StandUsage::default();
+    for flight in flights {
+        for (airport, time) in flight.get_airports().iter().zip(flight.get_times()) {
+            if !airport.get_iata_code().eq_ignore_ascii_case(iata_code) {
+                continue;
+            }
+            if let Some(terminal) = terminal
+                && !time.airside.terminal.as_deref().is_some_and(|recorded| recorded.eq_ignore_ascii_case(terminal)) {
+                continue;
+            }
+            match &time.airside.stand {
+                Some(Stand::Contact(_)) => usage.contact += 1,
+                Some(Stand::Remote(_)) => usage.remote += 1,
+                None => {},
+            }
+        }
+    }
+    return usage;
+}
+
+#[cfg(test)]
+mod test_airside {
+    use super::*;
+    use crate::domain::{flight::{FlightNumber, FlightTime}, test_support::{airport, get_test_aircraft_and_airline}, time_point::TimePoint};
+
+    // CA 1501 PEK -> SHA leaving from the given terminal and stand
+    fn at_pek(terminal: &str, stand: Stand) -> Result<Flight, FlightError> {
+        let pek = airport("PEK", "ZBAA", "Beijing", 40.0801, 116.5846, 116).with_terminals(vec!["T1".into(), "T2".into(), "T3".into()]);
+        let sha = airport("SHA", "ZSSS", "Shanghai", 31.1981, 121.3363, 10);
+        let mut departure = FlightTime::new(TimePoint::new(2025, 10, 1, 8, 0, 0, 8 * 60).unwrap(), TimePoint::new(2025, 10, 1, 8, 5, 0, 8 * 60).unwrap());
+        departure.airside = Airside { terminal: Some(terminal.into()), gate: Some("E21".into()), stand: Some(stand), runway: Some(Runway::new("36R").unwrap()) };
+        let arrival = FlightTime::new(TimePoint::new(2025, 10, 1, 10, 15, 0, 8 * 60).unwrap(), TimePoint::new(2025, 10, 1, 10, 10, 0, 8 * 60).unwrap());
+        let (aircraft, airline) = get_test_aircraft_and_airline();
+        return Flight::new(vec![FlightNumber::new("CA".into(), "1501".into())], vec![pek, sha], vec![departure, arrival], aircraft, airline);
+    }
+
+    #[test]
+    fn test_runway() {
+        assert_eq!(Runway::new("36r").unwrap().as_str(), "36R");
+        assert_eq!(Runway::new("9L").unwrap().to_string(), "09L");
+        assert_eq!(Runway::new("01").unwrap().as_str(), "01");
+        assert_eq!(Runway::new("37"), Err(FlightError::InvalidRunway("37".into())));
+        assert_eq!(Runway::new("00"), Err(FlightError::InvalidRunway("00".into())));
+        assert_eq!(Runway::new("18X"), Err(FlightError::InvalidRunway("18X".into())));
+        assert_eq!(Runway::new("R"), Err(FlightError::InvalidRunway("R".into())));
+    }
+
+    #[test]
+    fn test_stand() {
+        assert!(Stand::Remote("512".into()).is_remote());
+        assert!(!Stand::Contact("E21".into()).is_remote());
+        assert_eq!(Stand::Contact("E21".into()).get_name(), "E21");
+        assert_eq!(StandUsage { contact: 3, remote: 1 }.remote_share(), Some(0.25));
+        assert_eq!(StandUsage::default().remote_share(), None);
+    }
+
+    #[test]
+    fn test_terminal_and_stand_usage() {
+        assert_eq!(at_pek("T4", Stand::Contact("E21".into())).err(), Some(FlightError::UnknownTerminal { airport: "PEK".into(), terminal: "T4".into() }));
+        let flights = vec![
+            at_pek("T3", Stand::Remote("512".into())).unwrap(),
+            at_pek("t3", Stand::Remote("515".into())).unwrap(),
+            at_pek("T3", Stand::Contact("E21".into())).unwrap(),
+            at_pek("T2", Stand::Contact("25".into())).unwrap(),
+        ];
+        let t3 = stand_usage(&flights, "PEK", Some("T3"));
+        assert_eq!((t3.remote, t3.contact), (2, 1));
+        assert_eq!(stand_usage(&flights, "PEK", Some("t3")), t3);
+        assert_eq!(stand_usage(&flights, "pek", None).remote_share(), Some(0.5));
+        assert_eq!(stand_usage(&flights, "SHA", None).remote_share(), None);
+    }
+}
@@ -0,0 +1,57 @@
+use chrono::{Local, Utc};
+
+use crate::domain::time_point::TimePoint;
+
+// the single source of "now", so ages and upcoming trips can be tested against a fixed instant
+pub trait Clock {
+    fn now(&self) -> TimePoint;
+}
+
+// reads the machine clock in its local offset
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> TimePoint {
+        // fall back to UTC if the machine runs with an offset TimePoint does not accept
+        return TimePoint::try_from(Local::now().fixed_offset()).unwrap_or_else(|_| TimePoint::from(Utc::now()));
+    }
+}
+
+// always answers the same instant
+pub struct FixedClock {
+    now: TimePoint
+}
+
+impl FixedClock {
+    pub fn new(now: TimePoint) -> Self {
+        return FixedClock { now };
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> TimePoint {
+        return self.now.clone();
+    }
+}
+
+#[cfg(test)]
+mod test_clock {
+    use super::*;
+
+    #[test]
+    fn test_fixed_clock() {
+        let now = TimePoint::new(2025, 10, 18, 9, 30, 0, 8 * 60).unwrap();
+        let clock = FixedClock::new(now.clone());
+        assert_eq!(clock.now().to_string(), now.to_string());
+        assert_eq!(clock.now().to_string(), clock.now().to_string());
+    }
+
+    #[test]
+    fn test_system_clock_matches_utc() {
+        let before = TimePoint::from(Utc::now());
+        let now = SystemClock.now();
+        let after = TimePoint::from(Utc::now());
+        assert_eq!(now.validate(), Ok(()));
+        assert!(before <= now && now <= after);
+    }
+}
@@ -0,0 +1,575 @@
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+pub enum Continent {
+    Africa,
+    Antarctica,
+    Asia,
+    Europe,
+    NorthAmerica,
+    Oceania,
+    SouthAmerica
+}
+
+// an ISO 3166-1 country or territory, a reference into COUNTRIES so it is cheap to copy and stats can group on it
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct Country(&'static CountryData);
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CountryData {
+    alpha2: &'static str,
+    alpha3: &'static str,
+    name: &'static str,
+    continent: Continent,
+    registration_prefix: &'static str // nationality mark on aircraft, e.g. "B-" or "N"; empty when it borrows another's
+}
+
+impl CountryData {
+    const fn of(alpha2: &'static str, alpha3: &'static str, name: &'static str, continent: Continent, registration_prefix: &'static str) -> Self {
+        return CountryData { alpha2, alpha3, name, continent, registration_prefix };
+    }
+}
+
+impl Country {
+    pub fn from_alpha2(code: &str) -> Option<Country> {
+        let code = code.trim();
+        return COUNTRIES.iter().find(|country| country.alpha2.eq_ignore_ascii_case(code)).map(Country);
+    }
+
+    pub fn from_alpha3(code: &str) -> Option<Country> {
+        let code = code.trim();
+        return COUNTRIES.iter().find(|country| country.alpha3.eq_ignore_ascii_case(code)).map(Country);
+    }
+
+    // accepts either code, the English name or a common alternative name such as "UK" or "United States of America"
+    pub fn parse(text: &str) -> Result<Country, CountryError> {
+        let text = text.trim();
+        let by_name = |name: &str| COUNTRIES.iter().find(|country| country.name.eq_ignore_ascii_case(name)).map(Country);
+        let by_alias = || ALIASES.iter().find(|(alias, _)| alias.eq_ignore_ascii_case(text)).and_then(|(_, alpha2)| Country::from_alpha2(alpha2));
+        return Country::from_alpha2(text)
+            .or_else(|| Country::from_alpha3(text))
+            .or_else(|| by_name(text))
+            .or_else(by_alias)
+            .ok_or_else(|| CountryError::Unknown(text.to_string()));
+    }
+
+    // the country the first letters of an ICAO airport code are allocated to, e.g. "ZBAA" is China but "ZKPY" is North Korea
+    pub fn from_icao_airport_code(code: &str) -> Option<Country> {
+        let code = code.trim().to_ascii_uppercase();
+        return ICAO_PREFIXES.iter()
+            .filter(|(prefix, _)| code.starts_with(prefix))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .and_then(|(_, alpha2)| Country::from_alpha2(alpha2));
+    }
+
+    // the country of an aircraft registration, e.g. "B-2485" is China, "B-HNR" Hong Kong and "B-18701" Taiwan
+    pub fn from_registration(registration: &str) -> Option<Country> {
+        let registration = registration.trim().to_ascii_uppercase();
+        if let Some(mark) = registration.strip_prefix("B-") {
+            let alpha2 = match mark.chars().next()? {
+                'H' | 'K' | 'L' => "HK",
+                'M' => "MO",
+                _ if mark.len() == 5 && mark.chars().all(|c| c.is_ascii_digit()) => "TW",
+                _ => "CN",
+            };
+            return Country::from_alpha2(alpha2);
+        }
+        return COUNTRIES.iter()
+            .map(|country| (country.registration_prefix, country.alpha2))
+            .chain(EXTRA_REGISTRATION_PREFIXES.iter().copied())
+            .filter(|(prefix, _)| !prefix.is_empty() && registration.starts_with(prefix))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .and_then(|(_, alpha2)| Country::from_alpha2(alpha2));
+    }
+
+    pub fn get_alpha2(&self) -> &'static str {
+        return self.0.alpha2;
+    }
+
+    pub fn get_alpha3(&self) -> &'static str {
+        return self.0.alpha3;
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        return self.0.name;
+    }
+
+    pub fn get_continent(&self) -> Continent {
+        return self.0.continent;
+    }
+
+    pub fn get_registration_prefix(&self) -> &'static str {
+        return self.0.registration_prefix;
+    }
+
+    // the state a dependent territory belongs to, e.g. United Kingdom for Jersey; None for a sovereign state
+    pub fn get_sovereign(&self) -> Option<Country> {
+        return SOVEREIGNS.iter().find(|(territory, _)| *territory == self.0.alpha2).and_then(|(_, alpha2)| Country::from_alpha2(alpha2));
+    }
+}
+
+impl FromStr for Country {
+    type Err = CountryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        return Country::parse(s);
+    }
+}
+
+impl fmt::Debug for Country {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "Country({})", self.0.alpha2);
+    }
+}
+
+impl fmt::Display for Country {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "{}", self.0.name);
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum CountryError {
+    Unknown(String)
+}
+
+impl fmt::Display for CountryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            CountryError::Unknown(text) => write!(f, "\"{}\" is not a known country", text),
+        };
+    }
+}
+
+impl std::error::Error for CountryError {}
+
+// every ISO 3166-1 country and territory, plus Kosovo as OurAirports lists it
+pub const COUNTRIES: &[CountryData] = &[
+    CountryData::of("AD", "AND", "Andorra", Continent::Europe, "C3-"),
+    CountryData::of("AE", "ARE", "United Arab Emirates", Continent::Asia, "A6-"),
+    CountryData::of("AF", "AFG", "Afghanistan", Continent::Asia, "YA-"),
+    CountryData::of("AG", "ATG", "Antigua and Barbuda", Continent::NorthAmerica, "V2-"),
+    CountryData::of("AI", "AIA", "Anguilla", Continent::NorthAmerica, "VP-A"),
+    CountryData::of("AL", "ALB", "Albania", Continent::Europe, "ZA-"),
+    CountryData::of("AM", "ARM", "Armenia", Continent::Asia, "EK-"),
+    CountryData::of("AO", "AGO", "Angola", Continent::Africa, "D2-"),
+    CountryData::of("AQ", "ATA", "Antarctica", Continent::Antarctica, ""),
+    CountryData::of("AR", "ARG", "Argentina", Continent::SouthAmerica, "LV-"),
+    CountryData::of("AS", "ASM", "American Samoa", Continent::Oceania, ""),
+    CountryData::of("AT", "AUT", "Austria", Continent::Europe, "OE-"),
+    CountryData::of("AU", "AUS", "Australia", Continent::Oceania, "VH-"),
+    CountryData::of("AW", "ABW", "Aruba", Continent::NorthAmerica, "P4-"),
+    CountryData::of("AX", "ALA", "Åland Islands", Continent::Europe, ""),
+    CountryData::of("AZ", "AZE", "Azerbaijan", Continent::Asia, "4K-"),
+    CountryData::of("BA", "BIH", "Bosnia and Herzegovina", Continent::Europe, "E7-"),
+    CountryData::of("BB", "BRB", "Barbados", Continent::NorthAmerica, "8P-"),
+    CountryData::of("BD", "BGD", "Bangladesh", Continent::Asia, "S2-"),
+    CountryData::of("BE", "BEL", "Belgium", Continent::Europe, "OO-"),
+    CountryData::of("BF", "BFA", "Burkina Faso", Continent::Africa, "XT-"),
+    CountryData::of("BG", "BGR", "Bulgaria", Continent::Europe, "LZ-"),
+    CountryData::of("BH", "BHR", "Bahrain", Continent::Asia, "A9C-"),
+    CountryData::of("BI", "BDI", "Burundi", Continent::Africa, "9U-"),
+    CountryData::of("BJ", "BEN", "Benin", Continent::Africa, "TY-"),
+    CountryData::of("BL", "BLM", "Saint Barthélemy", Continent::NorthAmerica, ""),
+    CountryData::of("BM", "BMU", "Bermuda", Continent::NorthAmerica, "VP-B"),
+    CountryData::of("BN", "BRN", "Brunei", Continent::Asia, "V8-"),
+    CountryData::of("BO", "BOL", "Bolivia", Continent::SouthAmerica, "CP-"),
+    CountryData::of("BQ", "BES", "Caribbean Netherlands", Continent::NorthAmerica, ""),
+    CountryData::of("BR", "BRA", "Brazil", Continent::SouthAmerica, "PP-"),
+    CountryData::of("BS", "BHS", "Bahamas", Continent::NorthAmerica, "C6-"),
+    CountryData::of("BT", "BTN", "Bhutan", Continent::Asia, "A5-"),
+    CountryData::of("BV", "BVT", "Bouvet Island", Continent::Antarctica, ""),
+    CountryData::of("BW", "BWA", "Botswana", Continent::Africa, "A2-"),
+    CountryData::of("BY", "BLR", "Belarus", Continent::Europe, "EW-"),
+    CountryData::of("BZ", "BLZ", "Belize", Continent::NorthAmerica, "V3-"),
+    CountryData::of("CA", "CAN", "Canada", Continent::NorthAmerica, "C-"),
+    CountryData::of("CC", "CCK", "Cocos (Keeling) Islands", Continent::Asia, ""),
+    CountryData::of("CD", "COD", "Democratic Republic of the Congo", Continent::Africa, "9S-"),
+    CountryData::of("CF", "CAF", "Central African Republic", Continent::Africa, "TL-"),
+    CountryData::of("CG", "COG", "Republic of the Congo", Continent::Africa, "TN-"),
+    CountryData::of("CH", "CHE", "Switzerland", Continent::Europe, "HB-"),
+    CountryData::of("CI", "CIV", "Côte d'Ivoire", Continent::Africa, "TU-"),
+    CountryData::of("CK", "COK", "Cook Islands", Continent::Oceania, "E5-"),
+    CountryData::of("CL", "CHL", "Chile", Continent::SouthAmerica, "CC-"),
+    CountryData::of("CM", "CMR", "Cameroon", Continent::Africa, "TJ-"),
+    CountryData::of("CN", "CHN", "China", Continent::Asia, "B-"),
+    CountryData::of("CO", "COL", "Colombia", Continent::SouthAmerica, "HK-"),
+    CountryData::of("CR", "CRI", "Costa Rica", Continent::NorthAmerica, "TI-"),
+    CountryData::of("CU", "CUB", "Cuba", Continent::NorthAmerica, "CU-"),
+    CountryData::of("CV", "CPV", "Cabo Verde", Continent::Africa, "D4-"),
+    CountryData::of("CW", "CUW", "Curaçao", Continent::NorthAmerica, "PJ-"),
+    CountryData::of("CX", "CXR", "Christmas Island", Continent::Asia, ""),
+    CountryData::of("CY", "CYP", "Cyprus", Continent::Europe, "5B-"),
+    CountryData::of("CZ", "CZE", "Czechia", Continent::Europe, "OK-"),
+    CountryData::of("DE", "DEU", "Germany", Continent::Europe, "D-"),
+    CountryData::of("DJ", "DJI", "Djibouti", Continent::Africa, "J2-"),
+    CountryData::of("DK", "DNK", "Denmark", Continent::Europe, "OY-"),
+    CountryData::of("DM", "DMA", "Dominica", Continent::NorthAmerica, "J7-"),
+    CountryData::of("DO", "DOM", "Dominican Republic", Continent::NorthAmerica, "HI"),
+    CountryData::of("DZ", "DZA", "Algeria", Continent::Africa, "7T-"),
+    CountryData::of("EC", "ECU", "Ecuador", Continent::SouthAmerica, "HC-"),
+    CountryData::of("EE", "EST", "Estonia", Continent::Europe, "ES-"),
+    CountryData::of("EG", "EGY", "Egypt", Continent::Africa, "SU-"),
+    CountryData::of("EH", "ESH", "Western Sahara", Continent::Africa, ""),
+    CountryData::of("ER", "ERI", "Eritrea", Continent::Africa, "E3-"),
+    CountryData::of("ES", "ESP", "Spain", Continent::Europe, "EC-"),
+    CountryData::of("ET", "ETH", "Ethiopia", Continent::Africa, "ET-"),
+    CountryData::of("FI", "FIN", "Finland", Continent::Europe, "OH-"),
+    CountryData::of("FJ", "FJI", "Fiji", Continent::Oceania, "DQ-"),
+    CountryData::of("FK", "FLK", "Falkland Islands", Continent::SouthAmerica, "VP-F"),
+    CountryData::of("FM", "FSM", "Micronesia", Continent::Oceania, "V6-"),
+    CountryData::of("FO", "FRO", "Faroe Islands", Continent::Europe, ""),
+    CountryData::of("FR", "FRA", "France", Continent::Europe, "F-"),
+    CountryData::of("GA", "GAB", "Gabon", Continent::Africa, "TR-"),
+    CountryData::of("GB", "GBR", "United Kingdom", Continent::Europe, "G-"),
+    CountryData::of("GD", "GRD", "Grenada", Continent::NorthAmerica, "J3-"),
+    CountryData::of("GE", "GEO", "Georgia", Continent::Asia, "4L-"),
+    CountryData::of("GF", "GUF", "French Guiana", Continent::SouthAmerica, ""),
+    CountryData::of("GG", "GGY", "Guernsey", Continent::Europe, "2-"),
+    CountryData::of("GH", "GHA", "Ghana", Continent::Africa, "9G-"),
+    CountryData::of("GI", "GIB", "Gibraltar", Continent::Europe, "VP-G"),
+    CountryData::of("GL", "GRL", "Greenland", Continent::NorthAmerica, ""),
+    CountryData::of("GM", "GMB", "Gambia", Continent::Africa, "C5-"),
+    CountryData::of("GN", "GIN", "Guinea", Continent::Africa, "3X-"),
+    CountryData::of("GP", "GLP", "Guadeloupe", Continent::NorthAmerica, ""),
+    CountryData::of("GQ", "GNQ", "Equatorial Guinea", Continent::Africa, "3C-"),
+    CountryData::of("GR", "GRC", "Greece", Continent::Europe, "SX-"),
+    CountryData::of("GS", "SGS", "South Georgia and the South Sandwich Islands", Continent::Antarctica, ""),
+    CountryData::of("GT", "GTM", "Guatemala", Continent::NorthAmerica, "TG-"),
+    CountryData::of("GU", "GUM", "Guam", Continent::Oceania, ""),
+    CountryData::of("GW", "GNB", "Guinea-Bissau", Continent::Africa, "J5-"),
+    CountryData::of("GY", "GUY", "Guyana", Continent::SouthAmerica, "8R-"),
+    CountryData::of("HK", "HKG", "Hong Kong", Continent::Asia, "B-"),
+    CountryData::of("HM", "HMD", "Heard Island and McDonald Islands", Continent::Antarctica, ""),
+    CountryData::of("HN", "HND", "Honduras", Continent::NorthAmerica, "HR-"),
+    CountryData::of("HR", "HRV", "Croatia", Continent::Europe, "9A-"),
+    CountryData::of("HT", "HTI", "Haiti", Continent::NorthAmerica, "HH-"),
+    CountryData::of("HU", "HUN", "Hungary", Continent::Europe, "HA-"),
+    CountryData::of("ID", "IDN", "Indonesia", Continent::Asia, "PK-"),
+    CountryData::of("IE", "IRL", "Ireland", Continent::Europe, "EI-"),
+    CountryData::of("IL", "ISR", "Israel", Continent::Asia, "4X-"),
+    CountryData::of("IM", "IMN", "Isle of Man", Continent::Europe, "M-"),
+    CountryData::of("IN", "IND", "India", Continent::Asia, "VT-"),
+    CountryData::of("IO", "IOT", "British Indian Ocean Territory", Continent::Asia, ""),
+    CountryData::of("IQ", "IRQ", "Iraq", Continent::Asia, "YI-"),
+    CountryData::of("IR", "IRN", "Iran", Continent::Asia, "EP-"),
+    CountryData::of("IS", "ISL", "Iceland", Continent::Europe, "TF-"),
+    CountryData::of("IT", "ITA", "Italy", Continent::Europe, "I-"),
+    CountryData::of("JE", "JEY", "Jersey", Continent::Europe, "ZJ-"),
+    CountryData::of("JM", "JAM", "Jamaica", Continent::NorthAmerica, "6Y-"),
+    CountryData::of("JO", "JOR", "Jordan", Continent::Asia, "JY-"),
+    CountryData::of("JP", "JPN", "Japan", Continent::Asia, "JA"),
+    CountryData::of("KE", "KEN", "Kenya", Continent::Africa, "5Y-"),
+    CountryData::of("KG", "KGZ", "Kyrgyzstan", Continent::Asia, "EX-"),
+    CountryData::of("KH", "KHM", "Cambodia", Continent::Asia, "XU-"),
+    CountryData::of("KI", "KIR", "Kiribati", Continent::Oceania, "T3-"),
+    CountryData::of("KM", "COM", "Comoros", Continent::Africa, "D6-"),
+    CountryData::of("KN", "KNA", "Saint Kitts and Nevis", Continent::NorthAmerica, "V4-"),
+    CountryData::of("KP", "PRK", "North Korea", Continent::Asia, "P-"),
+    CountryData::of("KR", "KOR", "South Korea", Continent::Asia, "HL"),
+    CountryData::of("KW", "KWT", "Kuwait", Continent::Asia, "9K-"),
+    CountryData::of("KY", "CYM", "Cayman Islands", Continent::NorthAmerica, "VP-C"),
+    CountryData::of("KZ", "KAZ", "Kazakhstan", Continent::Asia, "UP-"),
+    CountryData::of("LA", "LAO", "Laos", Continent::Asia, "RDPL-"),
+    CountryData::of("LB", "LBN", "Lebanon", Continent::Asia, "OD-"),
+    CountryData::of("LC", "LCA", "Saint Lucia", Continent::NorthAmerica, "J6-"),
+    CountryData::of("LI", "LIE", "Liechtenstein", Continent::Europe, ""),
+    CountryData::of("LK", "LKA", "Sri Lanka", Continent::Asia, "4R-"),
+    CountryData::of("LR", "LBR", "Liberia", Continent::Africa, "A8-"),
+    CountryData::of("LS", "LSO", "Lesotho", Continent::Africa, "7P-"),
+    CountryData::of("LT", "LTU", "Lithuania", Continent::Europe, "LY-"),
+    CountryData::of("LU", "LUX", "Luxembourg", Continent::Europe, "LX-"),
+    CountryData::of("LV", "LVA", "Latvia", Continent::Europe, "YL-"),
+    CountryData::of("LY", "LBY", "Libya", Continent::Africa, "5A-"),
+    CountryData::of("MA", "MAR", "Morocco", Continent::Africa, "CN-"),
+    CountryData::of("MC", "MCO", "Monaco", Continent::Europe, "3A-"),
+    CountryData::of("MD", "MDA", "Moldova", Continent::Europe, "ER-"),
+    CountryData::of("ME", "MNE", "Montenegro", Continent::Europe, "4O-"),
+    CountryData::of("MF", "MAF", "Saint Martin", Continent::NorthAmerica, ""),
+    CountryData::of("MG", "MDG", "Madagascar", Continent::Africa, "5R-"),
+    CountryData::of("MH", "MHL", "Marshall Islands", Continent::Oceania, "V7-"),
+    CountryData::of("MK", "MKD", "North Macedonia", Continent::Europe, "Z3-"),
+    CountryData::of("ML", "MLI", "Mali", Continent::Africa, "TZ-"),
+    CountryData::of("MM", "MMR", "Myanmar", Continent::Asia, "XY-"),
+    CountryData::of("MN", "MNG", "Mongolia", Continent::Asia, "JU-"),
+    CountryData::of("MO", "MAC", "Macao", Continent::Asia, "B-"),
+    CountryData::of("MP", "MNP", "Northern Mariana Islands", Continent::Oceania, ""),
+    CountryData::of("MQ", "MTQ", "Martinique", Continent::NorthAmerica, ""),
+    CountryData::of("MR", "MRT", "Mauritania", Continent::Africa, "5T-"),
+    CountryData::of("MS", "MSR", "Montserrat", Continent::NorthAmerica, "VP-M"),
+    CountryData::of("MT", "MLT", "Malta", Continent::Europe, "9H-"),
+    CountryData::of("MU", "MUS", "Mauritius", Continent::Africa, "3B-"),
+    CountryData::of("MV", "MDV", "Maldives", Continent::Asia, "8Q-"),
+    CountryData::of("MW", "MWI", "Malawi", Continent::Africa, "7Q-"),
+    CountryData::of("MX", "MEX", "Mexico", Continent::NorthAmerica, "XA-"),
+    CountryData::of("MY", "MYS", "Malaysia", Continent::Asia, "9M-"),
+    CountryData::of("MZ", "MOZ", "Mozambique", Continent::Africa, "C9-"),
+    CountryData::of("NA", "NAM", "Namibia", Continent::Africa, "V5-"),
+    CountryData::of("NC", "NCL", "New Caledonia", Continent::Oceania, ""),
+    CountryData::of("NE", "NER", "Niger", Continent::Africa, "5U-"),
+    CountryData::of("NF", "NFK", "Norfolk Island", Continent::Oceania, ""),
+    CountryData::of("NG", "NGA", "Nigeria", Continent::Africa, "5N-"),
+    CountryData::of("NI", "NIC", "Nicaragua", Continent::NorthAmerica, "YN-"),
+    CountryData::of("NL", "NLD", "Netherlands", Continent::Europe, "PH-"),
+    CountryData::of("NO", "NOR", "Norway", Continent::Europe, "LN-"),
+    CountryData::of("NP", "NPL", "Nepal", Continent::Asia, "9N-"),
+    CountryData::of("NR", "NRU", "Nauru", Continent::Oceania, "C2-"),
+    CountryData::of("NU", "NIU", "Niue", Continent::Oceania, ""),
+    CountryData::of("NZ", "NZL", "New Zealand", Continent::Oceania, "ZK-"),
+    CountryData::of("OM", "OMN", "Oman", Continent::Asia, "A4O-"),
+    CountryData::of("PA", "PAN", "Panama", Continent::NorthAmerica, "HP-"),
+    CountryData::of("PE", "PER", "Peru", Continent::SouthAmerica, "OB-"),
+    CountryData::of("PF", "PYF", "French Polynesia", Continent::Oceania, ""),
+    CountryData::of("PG", "PNG", "Papua New Guinea", Continent::Oceania, "P2-"),
+    CountryData::of("PH", "PHL", "Philippines", Continent::Asia, "RP-"),
+    CountryData::of("PK", "PAK", "Pakistan", Continent::Asia, "AP-"),
+    CountryData::of("PL", "POL", "Poland", Continent::Europe, "SP-"),
+    CountryData::of("PM", "SPM", "Saint Pierre and Miquelon", Continent::NorthAmerica, ""),
+    CountryData::of("PN", "PCN", "Pitcairn Islands", Continent::Oceania, ""),
+    CountryData::of("PR", "PRI", "Puerto Rico", Continent::NorthAmerica, ""),
+    CountryData::of("PS", "PSE", "Palestine", Continent::Asia, ""),
+    CountryData::of("PT", "PRT", "Portugal", Continent::Europe, "CS-"),
+    CountryData::of("PW", "PLW", "Palau", Continent::Oceania, "T8A"),
+    CountryData::of("PY", "PRY", "Paraguay", Continent::SouthAmerica, "ZP-"),
+    CountryData::of("QA", "QAT", "Qatar", Continent::Asia, "A7-"),
+    CountryData::of("RE", "REU", "Réunion", Continent::Africa, ""),
+    CountryData::of("RO", "ROU", "Romania", Continent::Europe, "YR-"),
+    CountryData::of("RS", "SRB", "Serbia", Continent::Europe, "YU-"),
+    CountryData::of("RU", "RUS", "Russia", Continent::Europe, "RA-"),
+    CountryData::of("RW", "RWA", "Rwanda", Continent::Africa, "9XR-"),
+    CountryData::of("SA", "SAU", "Saudi Arabia", Continent::Asia, "HZ-"),
+    CountryData::of("SB", "SLB", "Solomon Islands", Continent::Oceania, "H4-"),
+    CountryData::of("SC", "SYC", "Seychelles", Continent::Africa, "S7-"),
+    CountryData::of("SD", "SDN", "Sudan", Continent::Africa, "ST-"),
+    CountryData::of("SE", "SWE", "Sweden", Continent::Europe, "SE-"),
+    CountryData::of("SG", "SGP", "Singapore", Continent::Asia, "9V-"),
+    CountryData::of("SH", "SHN", "Saint Helena, Ascension and Tristan da Cunha", Continent::Africa, "VQ-H"),
+    CountryData::of("SI", "SVN", "Slovenia", Continent::Europe, "S5-"),
+    CountryData::of("SJ", "SJM", "Svalbard and Jan Mayen", Continent::Europe, ""),
+    CountryData::of("SK", "SVK", "Slovakia", Continent::Europe, "OM-"),
+    CountryData::of("SL", "SLE", "Sierra Leone", Continent::Africa, "9L-"),
+    CountryData::of("SM", "SMR", "San Marino", Continent::Europe, "T7-"),
+    CountryData::of("SN", "SEN", "Senegal", Continent::Africa, "6V-"),
+    CountryData::of("SO", "SOM", "Somalia", Continent::Africa, "6O-"),
+    CountryData::of("SR", "SUR", "Suriname", Continent::SouthAmerica, "PZ-"),
+    CountryData::of("SS", "SSD", "South Sudan", Continent::Africa, "Z8-"),
+    CountryData::of("ST", "STP", "São Tomé and Príncipe", Continent::Africa, "S9-"),
+    CountryData::of("SV", "SLV", "El Salvador", Continent::NorthAmerica, "YS-"),
+    CountryData::of("SX", "SXM", "Sint Maarten", Continent::NorthAmerica, ""),
+    CountryData::of("SY", "SYR", "Syria", Continent::Asia, "YK-"),
+    CountryData::of("SZ", "SWZ", "Eswatini", Continent::Africa, "3D-"),
+    CountryData::of("TC", "TCA", "Turks and Caicos Islands", Continent::NorthAmerica, "VQ-T"),
+    CountryData::of("TD", "TCD", "Chad", Continent::Africa, "TT-"),
+    CountryData::of("TF", "ATF", "French Southern Territories", Continent::Antarctica, ""),
+    CountryData::of("TG", "TGO", "Togo", Continent::Africa, "5V-"),
+    CountryData::of("TH", "THA", "Thailand", Continent::Asia, "HS-"),
+    CountryData::of("TJ", "TJK", "Tajikistan", Continent::Asia, "EY-"),
+    CountryData::of("TK", "TKL", "Tokelau", Continent::Oceania, ""),
+    CountryData::of("TL", "TLS", "Timor-Leste", Continent::Asia, "4W-"),
+    CountryData::of("TM", "TKM", "Turkmenistan", Continent::Asia, "EZ-"),
+    CountryData::of("TN", "TUN", "Tunisia", Continent::Africa, "TS-"),
+    CountryData::of("TO", "TON", "Tonga", Continent::Oceania, "A3-"),
+    CountryData::of("TR", "TUR", "Türkiye", Continent::Asia, "TC-"),
+    CountryData::of("TT", "TTO", "Trinidad and Tobago", Continent::NorthAmerica, "9Y-"),
+    CountryData::of("TV", "TUV", "Tuvalu", Continent::Oceania, "T2-"),
+    CountryData::of("TW", "TWN", "Taiwan", Continent::Asia, "B-"),
+    CountryData::of("TZ", "TZA", "Tanzania", Continent::Africa, "5H-"),
+    CountryData::of("UA", "UKR", "Ukraine", Continent::Europe, "UR-"),
+    CountryData::of("UG", "UGA", "Uganda", Continent::Africa, "5X-"),
+    CountryData::of("UM", "UMI", "United States Minor Outlying Islands", Continent::Oceania, ""),
+    CountryData::of("US", "USA", "United States", Continent::NorthAmerica, "N"),
+    CountryData::of("UY", "URY", "Uruguay", Continent::SouthAmerica, "CX-"),
+    CountryData::of("UZ", "UZB", "Uzbekistan", Continent::Asia, "UK"),
+    CountryData::of("VA", "VAT", "Vatican City", Continent::Europe, ""),
+    CountryData::of("VC", "VCT", "Saint Vincent and the Grenadines", Continent::NorthAmerica, "J8-"),
+    CountryData::of("VE", "VEN", "Venezuela", Continent::SouthAmerica, "YV"),
+    CountryData::of("VG", "VGB", "British Virgin Islands", Continent::NorthAmerica, "VP-L"),
+    CountryData::of("VI", "VIR", "U.S. Virgin Islands", Continent::NorthAmerica, ""),
+    CountryData::of("VN", "VNM", "Vietnam", Continent::Asia, "VN-"),
+    CountryData::of("VU", "VUT", "Vanuatu", Continent::Oceania, "YJ-"),
+    CountryData::of("WF", "WLF", "Wallis and Futuna", Continent::Oceania, ""),
+    CountryData::of("WS", "WSM", "Samoa", Continent::Oceania, "5W-"),
+    CountryData::of("XK", "XKX", "Kosovo", Continent::Europe, ""), // user-assigned code, used by OurAirports
+    CountryData::of("YE", "YEM", "Yemen", Continent::Asia, "7O-"),
+    CountryData::of("YT", "MYT", "Mayotte", Continent::Africa, ""),
+    CountryData::of("ZA", "ZAF", "South Africa", Continent::Africa, "ZS-"),
+    CountryData::of("ZM", "ZMB", "Zambia", Continent::Africa, "9J-"),
+    CountryData::of("ZW", "ZWE", "Zimbabwe", Continent::Africa, "Z-"),
+];
+
+const ALIASES: &[(&str, &str)] = &[
+    ("United States of America", "US"), ("America", "US"), ("UK", "GB"), ("Great Britain", "GB"), ("Britain", "GB"),
+    ("PRC", "CN"), ("Mainland China", "CN"), ("Macau", "MO"), ("Republic of Korea", "KR"), ("Korea", "KR"),
+    ("Russian Federation", "RU"), ("Turkey", "TR"), ("Czech Republic", "CZ"), ("Holland", "NL"), ("Burma", "MM"),
+    ("Ivory Coast", "CI"), ("Cape Verde", "CV"), ("Swaziland", "SZ"), ("Viet Nam", "VN"),
+];
+
+// dependent territories and the state they belong to, e.g. Jersey airports carry British ICAO codes
+const SOVEREIGNS: &[(&str, &str)] = &[
+    ("AI", "GB"), ("BM", "GB"), ("FK", "GB"), ("GG", "GB"), ("GI", "GB"), ("GS", "GB"), ("IM", "GB"), ("IO", "GB"), ("JE", "GB"), ("KY", "GB"),
+    ("MS", "GB"), ("PN", "GB"), ("SH", "GB"), ("TC", "GB"), ("VG", "GB"),
+    ("AS", "US"), ("GU", "US"), ("MP", "US"), ("PR", "US"), ("UM", "US"), ("VI", "US"),
+    ("BL", "FR"), ("GF", "FR"), ("GP", "FR"), ("MF", "FR"), ("MQ", "FR"), ("NC", "FR"), ("PF", "FR"), ("PM", "FR"), ("RE", "FR"), ("TF", "FR"),
+    ("WF", "FR"), ("YT", "FR"),
+    ("AW", "NL"), ("BQ", "NL"), ("CW", "NL"), ("SX", "NL"), ("CC", "AU"), ("CX", "AU"), ("HM", "AU"), ("NF", "AU"), ("CK", "NZ"), ("NU", "NZ"),
+    ("TK", "NZ"), ("FO", "DK"), ("GL", "DK"), ("BV", "NO"), ("SJ", "NO"), ("AX", "FI"), ("HK", "CN"), ("MO", "CN"),
+];
+
+// registration marks besides each country's main one
+const EXTRA_REGISTRATION_PREFIXES: &[(&str, &str)] = &[
+    ("LQ-", "AR"), ("PR-", "BR"), ("PS-", "BR"), ("PT-", "BR"), ("PU-", "BR"), ("VQ-B", "BM"), ("9Q-", "CD"),
+    ("XB-", "MX"), ("XC-", "MX"), ("XZ-", "MM"), ("ZT-", "ZA"), ("ZU-", "ZA"),
+];
+
+// ICAO Doc 7910 nationality prefixes of airport codes, the longest matching prefix wins. A territory may share
+// its state's prefix (Jersey is EGJJ), see `get_sovereign`. I, J, Q, X and unallocated pairs such as "AA"
+// have no region, codes starting with them are not checked against a country.
+const ICAO_PREFIXES: &[(&str, &str)] = &[
+    ("AG", "SB"), ("AN", "NR"), ("AY", "PG"),
+    ("BG", "GL"), ("BI", "IS"), ("BK", "XK"),
+    ("C", "CA"),
+    ("DA", "DZ"), ("DB", "BJ"), ("DF", "BF"), ("DG", "GH"), ("DI", "CI"), ("DN", "NG"), ("DR", "NE"), ("DT", "TN"), ("DX", "TG"),
+    ("EB", "BE"), ("ED", "DE"), ("EE", "EE"), ("EF", "FI"), ("EG", "GB"), ("EH", "NL"), ("EI", "IE"), ("EK", "DK"), ("EL", "LU"), ("EN", "NO"),
+    ("EP", "PL"), ("ES", "SE"), ("ET", "DE"), ("EV", "LV"), ("EY", "LT"),
+    ("FA", "ZA"), ("FB", "BW"), ("FC", "CG"), ("FD", "SZ"), ("FE", "CF"), ("FG", "GQ"), ("FH", "SH"), ("FI", "MU"), ("FJ", "IO"), ("FK", "CM"),
+    ("FL", "ZM"), ("FM", "MG"), ("FMC", "KM"), ("FMCZ", "YT"), ("FME", "RE"), ("FN", "AO"), ("FO", "GA"), ("FP", "ST"), ("FQ", "MZ"),
+    ("FS", "SC"), ("FT", "TD"), ("FV", "ZW"), ("FW", "MW"), ("FX", "LS"), ("FY", "NA"), ("FZ", "CD"),
+    ("GA", "ML"), ("GB", "GM"), ("GC", "ES"), ("GE", "ES"), ("GF", "SL"), ("GG", "GW"), ("GL", "LR"), ("GM", "MA"), ("GO", "SN"), ("GQ", "MR"),
+    ("GS", "EH"), ("GU", "GN"), ("GV", "CV"),
+    ("HA", "ET"), ("HB", "BI"), ("HC", "SO"), ("HD", "DJ"), ("HE", "EG"), ("HH", "ER"), ("HJ", "SS"), ("HK", "KE"), ("HL", "LY"), ("HR", "RW"),
+    ("HS", "SD"), ("HT", "TZ"), ("HU", "UG"),
+    ("K", "US"),
+    ("LA", "AL"), ("LB", "BG"), ("LC", "CY"), ("LD", "HR"), ("LE", "ES"), ("LF", "FR"), ("LG", "GR"), ("LH", "HU"), ("LI", "IT"), ("LJ", "SI"),
+    ("LK", "CZ"), ("LL", "IL"), ("LM", "MT"), ("LN", "MC"), ("LO", "AT"), ("LP", "PT"), ("LQ", "BA"), ("LR", "RO"), ("LS", "CH"), ("LT", "TR"),
+    ("LU", "MD"), ("LV", "PS"), ("LW", "MK"), ("LX", "GI"), ("LY", "RS"), ("LYPG", "ME"), ("LYTV", "ME"), ("LZ", "SK"),
+    ("MB", "TC"), ("MD", "DO"), ("MG", "GT"), ("MH", "HN"), ("MK", "JM"), ("MM", "MX"), ("MN", "NI"), ("MP", "PA"), ("MR", "CR"), ("MS", "SV"),
+    ("MT", "HT"), ("MU", "CU"), ("MW", "KY"), ("MY", "BS"), ("MZ", "BZ"),
+    ("NC", "CK"), ("NF", "FJ"), ("NFT", "TO"), ("NG", "KI"), ("NGF", "TV"), ("NI", "NU"), ("NL", "WF"), ("NS", "WS"), ("NST", "AS"), ("NT", "PF"),
+    ("NV", "VU"), ("NW", "NC"), ("NZ", "NZ"),
+    ("OA", "AF"), ("OB", "BH"), ("OE", "SA"), ("OI", "IR"), ("OJ", "JO"), ("OK", "KW"), ("OL", "LB"), ("OM", "AE"), ("OO", "OM"), ("OP", "PK"),
+    ("OR", "IQ"), ("OS", "SY"), ("OT", "QA"), ("OY", "YE"),
+    ("PA", "US"), ("PB", "UM"), ("PC", "KI"), ("PF", "US"), ("PG", "GU"), ("PGRO", "MP"), ("PGSN", "MP"), ("PGWT", "MP"), ("PH", "US"), ("PJ", "UM"),
+    ("PK", "MH"), ("PL", "KI"), ("PM", "UM"), ("PO", "US"), ("PP", "US"), ("PT", "FM"), ("PTR", "PW"), ("PW", "UM"),
+    ("RC", "TW"), ("RJ", "JP"), ("RK", "KR"), ("RO", "JP"), ("RP", "PH"),
+    ("SA", "AR"), ("SB", "BR"), ("SC", "CL"), ("SD", "BR"), ("SE", "EC"), ("SF", "FK"), ("SG", "PY"), ("SI", "BR"), ("SJ", "BR"), ("SK", "CO"),
+    ("SL", "BO"), ("SM", "SR"), ("SN", "BR"), ("SO", "GF"), ("SP", "PE"), ("SS", "BR"), ("SU", "UY"), ("SV", "VE"), ("SW", "BR"), ("SY", "GY"),
+    ("TA", "AG"), ("TB", "BB"), ("TD", "DM"), ("TF", "GP"), ("TFFF", "MQ"), ("TFFG", "MF"), ("TFFJ", "BL"), ("TG", "GD"), ("TI", "VI"), ("TJ", "PR"),
+    ("TK", "KN"), ("TL", "LC"), ("TN", "NL"), ("TNCA", "AW"), ("TNCC", "CW"), ("TNCM", "SX"), ("TQ", "AI"), ("TR", "MS"), ("TT", "TT"), ("TU", "VG"),
+    ("TV", "VC"), ("TX", "BM"),
+    ("U", "RU"), ("UA", "KZ"), ("UAF", "KG"), ("UB", "AZ"), ("UC", "KG"), ("UD", "AM"), ("UG", "GE"), ("UK", "UA"), ("UM", "BY"), ("UMK", "RU"),
+    ("UT", "UZ"), ("UTA", "TM"), ("UTD", "TJ"),
+    ("VA", "IN"), ("VC", "LK"), ("VD", "KH"), ("VE", "IN"), ("VG", "BD"), ("VH", "HK"), ("VI", "IN"), ("VL", "LA"), ("VM", "MO"), ("VN", "NP"),
+    ("VO", "IN"), ("VQ", "BT"), ("VR", "MV"), ("VT", "TH"), ("VV", "VN"), ("VY", "MM"),
+    ("WA", "ID"), ("WB", "MY"), ("WBS", "BN"), ("WI", "ID"), ("WM", "MY"), ("WP", "TL"), ("WQ", "ID"), ("WR", "ID"), ("WS", "SG"),
+    ("Y", "AU"),
+    ("Z", "CN"), ("ZK", "KP"), ("ZM", "MN"),
+];
+
+#[cfg(test)]
+mod test_country {
+    use super::*;
+
+    #[test]
+    fn test_lookup() {
+        let us = Country::from_alpha2("us").unwrap();
+        assert_eq!(us.get_alpha3(), "USA");
+        assert_eq!(us.get_name(), "United States");
+        assert_eq!(us.get_continent(), Continent::NorthAmerica);
+        assert_eq!(Country::from_alpha3("CHN").unwrap().to_string(), "China");
+        for text in ["US", "USA", "United States", "united states of america"] {
+            assert_eq!(Country::parse(text), Ok(us));
+        }
+        assert_eq!("UK".parse::<Country>().unwrap().get_alpha2(), "GB");
+        assert_eq!(Country::parse("Atlantis"), Err(CountryError::Unknown("Atlantis".into())));
+        assert_eq!(COUNTRIES.len(), 250);
+        for code in ["JE", "GG", "SX", "FK", "BQ", "BL", "MF", "MS", "NF", "NU", "CX", "CC", "YT", "PM", "SH", "AX"] {
+            assert!(Country::from_alpha2(code).is_some(), "{}", code);
+        }
+        let sovereign = |code: &str| Country::from_alpha2(code).unwrap().get_sovereign().map(|country| country.get_alpha2());
+        assert_eq!(sovereign("JE"), Some("GB"));
+        assert_eq!(sovereign("SX"), Some("NL"));
+        assert_eq!(sovereign("GB"), None);
+    }
+
+    #[test]
+    fn test_codes_are_unique() {
+        for (index, (prefix, _)) in ICAO_PREFIXES.iter().enumerate() {
+            assert!(ICAO_PREFIXES[index + 1..].iter().all(|(other, _)| other != prefix), "{}", prefix);
+        }
+        for (index, country) in COUNTRIES.iter().enumerate() {
+            assert!(COUNTRIES[index + 1..].iter().all(|other| other.alpha2 != country.alpha2 && other.alpha3 != country.alpha3), "{}", country.alpha2);
+        }
+        assert!(ICAO_PREFIXES.iter().all(|(_, alpha2)| Country::from_alpha2(alpha2).is_some()));
+        assert!(EXTRA_REGISTRATION_PREFIXES.iter().all(|(_, alpha2)| Country::from_alpha2(alpha2).is_some()));
+        assert!(ALIASES.iter().all(|(_, alpha2)| Country::from_alpha2(alpha2).is_some()));
+        assert!(SOVEREIGNS.iter().all(|(territory, state)| Country::from_alpha2(territory).is_some() && Country::from_alpha2(state).is_some()));
+    }
+
+    #[test]
+    fn test_icao_airport_code() {
+        let region = |code: &str| Country::from_icao_airport_code(code).map(|country| country.get_alpha2());
+        assert_eq!(region("KJFK"), Some("US"));
+        assert_eq!(region("PHNL"), Some("US"));
+        assert_eq!(region("ZBAA"), Some("CN"));
+        assert_eq!(region("ZKPY"), Some("KP"));
+        assert_eq!(region("VHHH"), Some("HK"));
+        assert_eq!(region("WBSB"), Some("BN"));
+        assert_eq!(region("UUEE"), Some("RU"));
+        assert_eq!(region("UKBB"), Some("UA"));
+        assert_eq!(region("UTTT"), Some("UZ"));
+        assert_eq!(region("UTSS"), Some("UZ"));
+        assert_eq!(region("UTDD"), Some("TJ"));
+        assert_eq!(region("UTAA"), Some("TM"));
+        assert_eq!(region("UAAA"), Some("KZ"));
+        assert_eq!(region("UAFM"), Some("KG"));
+        assert_eq!(region("UMMS"), Some("BY"));
+        assert_eq!(region("UMKK"), Some("RU"));
+        assert_eq!(region("NFFN"), Some("FJ"));
+        assert_eq!(region("NFTF"), Some("TO"));
+        assert_eq!(region("AYPY"), Some("PG"));
+        assert_eq!(region("PGUM"), Some("GU"));
+        assert_eq!(region("PGSN"), Some("MP"));
+        assert_eq!(region("TNCB"), Some("NL")); // Bonaire is checked through the Netherlands, see get_sovereign
+        assert_eq!(region("TNCM"), Some("SX"));
+        assert_eq!(region("TFFR"), Some("GP"));
+        assert_eq!(region("TFFF"), Some("MQ"));
+        assert_eq!(region("FMEE"), Some("RE"));
+        assert_eq!(region("FMCZ"), Some("YT"));
+        assert_eq!(region("FMCH"), Some("KM"));
+        assert_eq!(region("FMMI"), Some("MG"));
+        assert_eq!(region("LYPG"), Some("ME"));
+        assert_eq!(region("LYBE"), Some("RS"));
+        assert_eq!(region("GCLP"), Some("ES"));
+        assert_eq!(region("UCFM"), Some("KG"));
+        assert_eq!(region("PTRO"), Some("PW"));
+        assert_eq!(region("PTKK"), Some("FM"));
+        // no region, so these go unchecked
+        for code in ["AAAA", "IAAA", "JAAA", "QAAA", "XAAA", "GZZZ"] {
+            assert_eq!(region(code), None, "{}", code);
+        }
+    }
+
+    #[test]
+    fn test_registration() {
+        let country = |registration: &str| Country::from_registration(registration).map(|country| country.get_alpha2());
+        assert_eq!(country("B-2485"), Some("CN"));
+        assert_eq!(country("B-30EE"), Some("CN"));
+        assert_eq!(country("B-HNR"), Some("HK"));
+        assert_eq!(country("B-MAN"), Some("MO"));
+        assert_eq!(country("B-18701"), Some("TW"));
+        assert_eq!(country("N123AA"), Some("US"));
+        assert_eq!(country("JA8089"), Some("JP"));
+        assert_eq!(country("HL7611"), Some("KR"));
+        assert_eq!(country("G-XLEA"), Some("GB"));
+        assert_eq!(country("C-FIUA"), Some("CA"));
+        assert_eq!(country("CC-BGA"), Some("CL"));
+        assert_eq!(country("9V-SKA"), Some("SG"));
+        assert_eq!(country("A9C-KA"), Some("BH"));
+        assert_eq!(country("VP-BLK"), Some("BM"));
+        assert_eq!(country("VQ-BIR"), Some("BM"));
+        assert_eq!(country("PR-XTA"), Some("BR"));
+        assert_eq!(country("ZJ-SRB"), Some("JE"));
+        assert_eq!(country("QQ-123"), None);
+    }
+}
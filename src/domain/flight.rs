@@ -1,21 +1,289 @@
-use crate::domain::{aircraft::Aircraft, airline::Airline, airport::Airport, time_point::TimePoint};
+use std::fmt;
+
+use crate::domain::{aircraft::Aircraft, airline::Airline, airport::Airport, airside::Airside, clock::Clock, flight_duration::{FlightDuration, SignedFlightDuration}, geodesic::{Distance, DistanceMethod}, time_point::{Precision, TimePoint, TimePointError}};
 
 pub struct Flight {
     flight_number: Vec<FlightNumber>, // 0: operator flight number, other: codeshare flight numbers
     city: Vec<Airport>, // 0: takeoff, last: landing, other: layovers
     time: Vec<FlightTime>, // 0: takeoff, last: landing, other: layovers
-    distance: i32, // unit: km
     aircraft: Aircraft,
     airline: Airline
 }
 
+impl Flight {
+    pub fn new(flight_number: Vec<FlightNumber>, city: Vec<Airport>, time: Vec<FlightTime>, aircraft: Aircraft, airline: Airline) -> Result<Self, FlightError> {
+        if city.len() < 2 {
+            return Err(FlightError::TooFewStops(city.len()));
+        }
+        if city.len() != time.len() {
+            return Err(FlightError::StopCountMismatch { airports: city.len(), times: time.len() });
+        }
+        let mut time = time;
+        for (airport, stop) in city.iter().zip(time.iter_mut()) {
+            // the airport was in service on that day
+            if stop.plan.precision >= Precision::Day && !airport.is_in_service_on(&stop.plan) {
+                let date = stop.plan.clone().with_precision(Precision::Day).map_err(FlightError::InvalidTime)?;
+                return Err(FlightError::AirportNotInService { airport: airport.get_code(), date: date.to_string() });
+            }
+            // a recorded terminal is one the airport has
+            if let Some(terminal) = &stop.airside.terminal
+                && !airport.has_terminal(terminal) {
+                return Err(FlightError::UnknownTerminal { airport: airport.get_iata_code().to_string(), terminal: terminal.clone() });
+            }
+            let Some(zone) = airport.get_zone() else {
+                continue;
+            };
+            for time_point in [&mut stop.plan, &mut stop.actual] {
+                if !time_point.is_exact() {
+                    continue;
+                }
+                // the airport's offset at that instant, not e.g. the departure's copied to the arrival
+                let mut local = time_point.clone();
+                local.to_zone(zone).map_err(FlightError::InvalidTime)?;
+                if local.utc_offset != time_point.utc_offset {
+                    return Err(FlightError::OffsetMismatch { airport: airport.get_iata_code().to_string(), expected: local.utc_offset, found: time_point.utc_offset });
+                }
+                *time_point = local; // takes on the airport's zone
+            }
+        }
+        return Ok(Flight { flight_number, city, time, aircraft, airline });
+    }
+
+    pub fn get_departure(&self) -> Option<&FlightTime> {
+        return self.time.first();
+    }
+
+    pub fn get_arrival(&self) -> Option<&FlightTime> {
+        return self.time.last();
+    }
+
+    pub fn get_flight_numbers(&self) -> &[FlightNumber] {
+        return &self.flight_number;
+    }
+
+    pub fn get_aircraft(&self) -> &Aircraft {
+        return &self.aircraft;
+    }
+
+    pub fn get_airline(&self) -> &Airline {
+        return &self.airline;
+    }
+
+    pub fn get_airports(&self) -> &[Airport] {
+        return &self.city;
+    }
+
+    pub fn get_times(&self) -> &[FlightTime] {
+        return &self.time;
+    }
+
+    // gate to gate as actually flown; unknown when only dates were recorded or the arrival
+    // is recorded before the departure
+    pub fn block_time(&self) -> Option<FlightDuration> {
+        if self.time.len() < 2 {
+            return None;
+        }
+        let (departure, arrival) = (self.get_departure()?, self.get_arrival()?);
+        if !departure.actual.is_exact() || !arrival.actual.is_exact() {
+            return None;
+        }
+        return (&arrival.actual - &departure.actual).to_unsigned();
+    }
+
+    pub fn scheduled_block_time(&self) -> Option<FlightDuration> {
+        if self.time.len() < 2 {
+            return None;
+        }
+        let (departure, arrival) = (self.get_departure()?, self.get_arrival()?);
+        if !departure.plan.is_exact() || !arrival.plan.is_exact() {
+            return None;
+        }
+        return (&arrival.plan - &departure.plan).to_unsigned();
+    }
+
+    pub fn arrival_delay(&self) -> Option<SignedFlightDuration> {
+        return self.get_arrival().and_then(|arrival| arrival.delay());
+    }
+
+    // great-circle distance summed over every leg, takeoff -> layovers -> landing;
+    // None if Vincenty does not converge on any leg
+    pub fn distance(&self, method: DistanceMethod) -> Option<Distance> {
+        return self.city.windows(2).map(|leg| leg[0].distance_to(&leg[1], method)).sum();
+    }
+
+    // judged by the scheduled takeoff, since the actual one is not known yet
+    pub fn is_upcoming(&self, clock: &dyn Clock) -> bool {
+        return self.get_departure().is_some_and(|departure| departure.plan > clock.now());
+    }
+}
 
 pub struct FlightTime {
     pub plan: TimePoint,
-    pub actual: TimePoint
+    pub actual: TimePoint,
+    pub airside: Airside // terminal, gate, stand and runway used at this stop
+}
+
+impl FlightTime {
+    pub fn new(plan: TimePoint, actual: TimePoint) -> Self {
+        return FlightTime { plan, actual, airside: Airside::default() };
+    }
+
+    // positive when late, negative when early; unknown unless both times are exact
+    pub fn delay(&self) -> Option<SignedFlightDuration> {
+        if !self.plan.is_exact() || !self.actual.is_exact() {
+            return None;
+        }
+        return Some(&self.actual - &self.plan);
+    }
 }
 
 pub struct FlightNumber {
     airline_code: String,
     flight_code: String
 }
+
+impl FlightNumber {
+    pub fn new(airline_code: String, flight_code: String) -> Self {
+        return FlightNumber { airline_code, flight_code };
+    }
+
+    pub fn get_airline_code(&self) -> &str {
+        return &self.airline_code;
+    }
+
+    pub fn get_flight_code(&self) -> &str {
+        return &self.flight_code;
+    }
+}
+
+impl fmt::Display for FlightNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "{}{}", self.airline_code, self.flight_code);
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum FlightError {
+    TooFewStops(usize),
+    StopCountMismatch { airports: usize, times: usize },
+    AirportNotInService { airport: String, date: String },
+    UnknownTerminal { airport: String, terminal: String },
+    InvalidRunway(String),
+    OffsetMismatch { airport: String, expected: i32, found: i32 }, // unit: minutes east of UTC
+    InvalidTime(TimePointError)
+}
+
+impl fmt::Display for FlightError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            FlightError::TooFewStops(stops) => write!(f, "a flight needs at least 2 airports, got {}", stops),
+            FlightError::StopCountMismatch { airports, times } => write!(f, "{} airports but {} times", airports, times),
+            FlightError::AirportNotInService { airport, date } => write!(f, "{} was not in service on {}", airport, date),
+            FlightError::UnknownTerminal { airport, terminal } => write!(f, "{} has no terminal {}", airport, terminal),
+            FlightError::InvalidRunway(designator) => write!(f, "\"{}\" is not a runway designator", designator),
+            FlightError::OffsetMismatch { airport, expected, found } => write!(f, "time at {} has UTC offset {} minutes, local clocks there showed {}", airport, found, expected),
+            FlightError::InvalidTime(error) => write!(f, "{}", error),
+        };
+    }
+}
+
+impl std::error::Error for FlightError {}
+
+#[cfg(test)]
+mod test_flight {
+    use super::*;
+    use crate::domain::test_support::{airport, get_test_aircraft_and_airline};
+
+    // CA 937 PEK -> LHR with a hypothetical stop in DXB
+    fn get_test_use(city: Vec<Airport>) -> Flight {
+        let (aircraft, airline) = get_test_aircraft_and_airline();
+        return Flight {
+            flight_number: vec![FlightNumber::new("CA".into(), "937".into())],
+            city,
+            time: Vec::new(),
+            aircraft,
+            airline
+        };
+    }
+
+    #[test]
+    fn test_new_checks_local_offsets() {
+        let pek = airport("PEK", "ZBAA", "Beijing", 40.0801, 116.5846, 116).with_zone(chrono_tz::Asia::Shanghai);
+        let lhr = airport("LHR", "EGLL", "London", 51.4706, -0.461941, 83).with_zone(chrono_tz::Europe::London);
+        let ca937 = || vec![FlightNumber::new("CA".into(), "937".into())];
+        let departure = pek.local_time(2025, 7, 1, 13, 0, 0).unwrap();
+        let arrival = lhr.local_time(2025, 7, 1, 17, 55, 0).unwrap();
+        let stops = |arrival: TimePoint| vec![
+            FlightTime::new(departure.clone(), departure.clone()),
+            FlightTime::new(arrival.clone(), arrival)
+        ];
+
+        let (aircraft, airline) = get_test_aircraft_and_airline();
+        let flight = Flight::new(ca937(), vec![pek.clone(), lhr.clone()], stops(arrival.clone()), aircraft, airline).unwrap();
+        assert_eq!(flight.block_time(), Some(FlightDuration::from_hours_minutes(11, 55)));
+        assert_eq!(flight.get_flight_numbers()[0].to_string(), "CA937");
+        assert_eq!(flight.get_airline().get_code(), "CA");
+        assert_eq!(flight.get_aircraft().get_reg_code(), "B-2485");
+
+        // arrival typed with the departure's +08:00
+        let (aircraft, airline) = get_test_aircraft_and_airline();
+        let copied = TimePoint::new(2025, 7, 1, 17, 55, 0, 8 * 60).unwrap();
+        assert_eq!(Flight::new(ca937(), vec![pek.clone(), lhr.clone()], stops(copied), aircraft, airline).err(),
+            Some(FlightError::OffsetMismatch { airport: "LHR".into(), expected: 60, found: 8 * 60 }));
+
+        // the right fixed offset is accepted and takes on the airport's zone
+        let (aircraft, airline) = get_test_aircraft_and_airline();
+        let fixed = TimePoint::new(2025, 7, 1, 17, 55, 0, 60).unwrap();
+        let flight = Flight::new(ca937(), vec![pek.clone(), lhr.clone()], stops(fixed), aircraft, airline).unwrap();
+        assert_eq!(flight.get_arrival().unwrap().actual.zone, Some(chrono_tz::Europe::London));
+
+        // a landing typed before the takeoff has no block time rather than a positive one
+        let (aircraft, airline) = get_test_aircraft_and_airline();
+        let too_early = lhr.local_time(2025, 7, 1, 5, 55, 0).unwrap();
+        let flight = Flight::new(ca937(), vec![pek.clone(), lhr.clone()], stops(too_early), aircraft, airline).unwrap();
+        assert_eq!(flight.block_time(), None);
+        assert_eq!(flight.scheduled_block_time(), None);
+
+        // Kai Tak's code HKG went to Chek Lap Kok on 6 July 1998
+        let kai_tak = airport("HKG", "VHHX", "Hong Kong", 22.3287, 114.194, 15)
+            .with_validity(None, Some(TimePoint::new_date(1998, 7, 6, 8 * 60).unwrap()))
+            .unwrap();
+        let (aircraft, airline) = get_test_aircraft_and_airline();
+        assert_eq!(Flight::new(ca937(), vec![pek.clone(), kai_tak], stops(arrival.clone()), aircraft, airline).err(),
+            Some(FlightError::AirportNotInService { airport: "HKG/VHHX".into(), date: "2025-07-01".into() }));
+
+        let (aircraft, airline) = get_test_aircraft_and_airline();
+        assert_eq!(Flight::new(ca937(), vec![pek.clone()], stops(arrival.clone()), aircraft, airline).err(), Some(FlightError::TooFewStops(1)));
+        let (aircraft, airline) = get_test_aircraft_and_airline();
+        assert_eq!(Flight::new(ca937(), vec![pek, lhr], Vec::new(), aircraft, airline).err(),
+            Some(FlightError::StopCountMismatch { airports: 2, times: 0 }));
+    }
+
+    #[test]
+    fn test_route_distance() {
+        let pek = airport("PEK", "ZBAA", "Beijing", 40.0801, 116.5846, 116);
+        let dxb = airport("DXB", "OMDB", "Dubai", 25.2528, 55.3644, 62);
+        let lhr = airport("LHR", "EGLL", "London", 51.4706, -0.461941, 83);
+
+        let nonstop = get_test_use(vec![pek.clone(), lhr.clone()]);
+        let direct = nonstop.distance(DistanceMethod::Vincenty).unwrap();
+        assert_eq!(Some(direct), pek.distance_to(&lhr, DistanceMethod::Vincenty));
+        assert!((direct.as_km() - 8160.0).abs() < 20.0);
+
+        let via_dxb = get_test_use(vec![pek.clone(), dxb.clone(), lhr.clone()]);
+        let total = via_dxb.distance(DistanceMethod::Haversine).unwrap();
+        assert_eq!(total, pek.distance_to(&dxb, DistanceMethod::Haversine).unwrap() + dxb.distance_to(&lhr, DistanceMethod::Haversine).unwrap());
+        assert!(total > direct);
+        assert_eq!(get_test_use(vec![pek]).distance(DistanceMethod::Vincenty), Some(Distance::default()));
+    }
+
+    #[test]
+    fn test_delay() {
+        let late = FlightTime::new(TimePoint::new(2025, 10, 1, 13, 0, 0, 8 * 60).unwrap(), TimePoint::new(2025, 10, 1, 5, 25, 0, 0).unwrap());
+        assert_eq!(late.delay(), Some(SignedFlightDuration::from_minutes(25)));
+        let early = FlightTime::new(late.plan.clone(), late.plan.checked_sub(FlightDuration::from_minutes(10)).unwrap());
+        assert_eq!(early.delay().unwrap().to_string(), "-0h 10m");
+        let remembered = FlightTime::new(late.plan.clone(), TimePoint::new_date(2025, 10, 1, 8 * 60).unwrap());
+        assert_eq!(remembered.delay(), None);
+    }
+}
@@ -0,0 +1,344 @@
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, Neg, Sub};
+
+use chrono::TimeDelta;
+
+// a length of time that cannot be negative, e.g. block time or layover
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy, Default)]
+pub struct FlightDuration {
+    seconds: u64
+}
+
+// a length of time that may be negative, e.g. a delay where early is below zero
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy, Default)]
+pub struct SignedFlightDuration {
+    seconds: i64
+}
+
+// constructors and operators saturate at the ends of the range, the checked_* methods report it instead
+
+impl FlightDuration {
+    pub fn from_seconds(seconds: u64) -> Self {
+        return FlightDuration { seconds };
+    }
+
+    pub fn from_minutes(minutes: u64) -> Self {
+        return FlightDuration { seconds: minutes.saturating_mul(60) };
+    }
+
+    pub fn from_hours(hours: u64) -> Self {
+        return FlightDuration { seconds: hours.saturating_mul(3600) };
+    }
+
+    pub fn from_hours_minutes(hours: u64, minutes: u64) -> Self {
+        return FlightDuration { seconds: hours.saturating_mul(3600).saturating_add(minutes.saturating_mul(60)) };
+    }
+
+    pub fn checked_from_hours_minutes(hours: u64, minutes: u64) -> Result<Self, FlightDurationError> {
+        let seconds = hours.checked_mul(3600)
+            .zip(minutes.checked_mul(60))
+            .and_then(|(hours, minutes)| hours.checked_add(minutes))
+            .ok_or(FlightDurationError::OutOfRange)?;
+        return Ok(FlightDuration { seconds });
+    }
+
+    pub fn as_seconds(&self) -> u64 {
+        return self.seconds;
+    }
+
+    pub fn as_minutes(&self) -> u64 {
+        return self.seconds / 60;
+    }
+
+    pub fn hours(&self) -> u64 {
+        return self.seconds / 3600;
+    }
+
+    pub fn minutes(&self) -> u64 {
+        return self.seconds % 3600 / 60;
+    }
+
+    // fails only for lengths beyond i64 seconds
+    pub fn to_signed(self) -> Result<SignedFlightDuration, FlightDurationError> {
+        let seconds = i64::try_from(self.seconds).map_err(|_| FlightDurationError::OutOfRange)?;
+        return Ok(SignedFlightDuration { seconds });
+    }
+
+    pub fn checked_add(self, rhs: FlightDuration) -> Result<FlightDuration, FlightDurationError> {
+        let seconds = self.seconds.checked_add(rhs.seconds).ok_or(FlightDurationError::OutOfRange)?;
+        return Ok(FlightDuration { seconds });
+    }
+
+    // the difference may well be negative, e.g. actual minus scheduled block time
+    pub fn checked_sub(self, rhs: FlightDuration) -> Result<SignedFlightDuration, FlightDurationError> {
+        let seconds = self.to_signed()?.seconds.checked_sub(rhs.to_signed()?.seconds).ok_or(FlightDurationError::OutOfRange)?;
+        return Ok(SignedFlightDuration { seconds });
+    }
+
+    pub fn average<'a, I: IntoIterator<Item = &'a FlightDuration>>(durations: I) -> Option<FlightDuration> {
+        // summed in u128 so no realistic number of durations can overflow, the mean always fits again
+        let (total, count) = durations.into_iter().fold((0u128, 0u128), |(total, count), d| (total + d.seconds as u128, count + 1));
+        if count == 0 {
+            return None;
+        }
+        return Some(FlightDuration { seconds: (total / count) as u64 });
+    }
+}
+
+impl SignedFlightDuration {
+    pub fn from_seconds(seconds: i64) -> Self {
+        return SignedFlightDuration { seconds };
+    }
+
+    pub fn from_minutes(minutes: i64) -> Self {
+        return SignedFlightDuration { seconds: minutes.saturating_mul(60) };
+    }
+
+    pub fn from_hours(hours: i64) -> Self {
+        return SignedFlightDuration { seconds: hours.saturating_mul(3600) };
+    }
+
+    pub fn from_hours_minutes(hours: i64, minutes: i64) -> Self {
+        return SignedFlightDuration { seconds: hours.saturating_mul(3600).saturating_add(minutes.saturating_mul(60)) };
+    }
+
+    pub fn checked_from_hours_minutes(hours: i64, minutes: i64) -> Result<Self, FlightDurationError> {
+        let seconds = hours.checked_mul(3600)
+            .zip(minutes.checked_mul(60))
+            .and_then(|(hours, minutes)| hours.checked_add(minutes))
+            .ok_or(FlightDurationError::OutOfRange)?;
+        return Ok(SignedFlightDuration { seconds });
+    }
+
+    pub fn checked_add(self, rhs: SignedFlightDuration) -> Result<SignedFlightDuration, FlightDurationError> {
+        let seconds = self.seconds.checked_add(rhs.seconds).ok_or(FlightDurationError::OutOfRange)?;
+        return Ok(SignedFlightDuration { seconds });
+    }
+
+    pub fn checked_sub(self, rhs: SignedFlightDuration) -> Result<SignedFlightDuration, FlightDurationError> {
+        let seconds = self.seconds.checked_sub(rhs.seconds).ok_or(FlightDurationError::OutOfRange)?;
+        return Ok(SignedFlightDuration { seconds });
+    }
+
+    pub fn checked_neg(self) -> Result<SignedFlightDuration, FlightDurationError> {
+        let seconds = self.seconds.checked_neg().ok_or(FlightDurationError::OutOfRange)?;
+        return Ok(SignedFlightDuration { seconds });
+    }
+
+    pub fn as_seconds(&self) -> i64 {
+        return self.seconds;
+    }
+
+    pub fn is_negative(&self) -> bool {
+        return self.seconds < 0;
+    }
+
+    pub fn abs(&self) -> FlightDuration {
+        return FlightDuration { seconds: self.seconds.unsigned_abs() };
+    }
+
+    // None when negative, e.g. a landing recorded before the takeoff
+    pub fn to_unsigned(self) -> Option<FlightDuration> {
+        return u64::try_from(self.seconds).ok().map(FlightDuration::from_seconds);
+    }
+
+    pub fn average<'a, I: IntoIterator<Item = &'a SignedFlightDuration>>(durations: I) -> Option<SignedFlightDuration> {
+        let (total, count) = durations.into_iter().fold((0i128, 0i128), |(total, count), d| (total + d.seconds as i128, count + 1));
+        if count == 0 {
+            return None;
+        }
+        return Some(SignedFlightDuration { seconds: (total / count) as i64 });
+    }
+}
+
+impl TryFrom<FlightDuration> for SignedFlightDuration {
+    type Error = FlightDurationError;
+
+    fn try_from(value: FlightDuration) -> Result<Self, Self::Error> {
+        return value.to_signed();
+    }
+}
+
+// durations count whole seconds, so a fractional second is rejected rather than cut off
+impl TryFrom<TimeDelta> for SignedFlightDuration {
+    type Error = FlightDurationError;
+
+    fn try_from(value: TimeDelta) -> Result<Self, Self::Error> {
+        if value.subsec_nanos() != 0 {
+            return Err(FlightDurationError::FractionalSeconds(value));
+        }
+        return Ok(SignedFlightDuration { seconds: value.num_seconds() });
+    }
+}
+
+impl TryFrom<SignedFlightDuration> for TimeDelta {
+    type Error = FlightDurationError;
+
+    fn try_from(value: SignedFlightDuration) -> Result<Self, Self::Error> {
+        return TimeDelta::try_seconds(value.seconds).ok_or(FlightDurationError::OutOfRange);
+    }
+}
+
+impl Add for FlightDuration {
+    type Output = FlightDuration;
+
+    fn add(self, rhs: FlightDuration) -> FlightDuration {
+        return FlightDuration { seconds: self.seconds.saturating_add(rhs.seconds) };
+    }
+}
+
+impl Add for SignedFlightDuration {
+    type Output = SignedFlightDuration;
+
+    fn add(self, rhs: SignedFlightDuration) -> SignedFlightDuration {
+        return SignedFlightDuration { seconds: self.seconds.saturating_add(rhs.seconds) };
+    }
+}
+
+impl Sub for SignedFlightDuration {
+    type Output = SignedFlightDuration;
+
+    fn sub(self, rhs: SignedFlightDuration) -> SignedFlightDuration {
+        return SignedFlightDuration { seconds: self.seconds.saturating_sub(rhs.seconds) };
+    }
+}
+
+impl Neg for SignedFlightDuration {
+    type Output = SignedFlightDuration;
+
+    fn neg(self) -> SignedFlightDuration {
+        return SignedFlightDuration { seconds: self.seconds.saturating_neg() };
+    }
+}
+
+impl Sum for FlightDuration {
+    fn sum<I: Iterator<Item = FlightDuration>>(iter: I) -> Self {
+        return iter.fold(FlightDuration::default(), |total, d| total + d);
+    }
+}
+
+impl<'a> Sum<&'a FlightDuration> for FlightDuration {
+    fn sum<I: Iterator<Item = &'a FlightDuration>>(iter: I) -> Self {
+        return iter.copied().sum();
+    }
+}
+
+impl Sum for SignedFlightDuration {
+    fn sum<I: Iterator<Item = SignedFlightDuration>>(iter: I) -> Self {
+        return iter.fold(SignedFlightDuration::default(), |total, d| total + d);
+    }
+}
+
+impl<'a> Sum<&'a SignedFlightDuration> for SignedFlightDuration {
+    fn sum<I: Iterator<Item = &'a SignedFlightDuration>>(iter: I) -> Self {
+        return iter.copied().sum();
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum FlightDurationError {
+    FractionalSeconds(TimeDelta), // the chrono value that is not a whole number of seconds
+    OutOfRange // the length does not fit the target type
+}
+
+impl fmt::Display for FlightDurationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            FlightDurationError::FractionalSeconds(delta) => write!(f, "duration {} is not a whole number of seconds", delta),
+            FlightDurationError::OutOfRange => write!(f, "duration is out of range"),
+        };
+    }
+}
+
+impl std::error::Error for FlightDurationError {}
+
+// e.g. "11h 05m", seconds are dropped
+impl fmt::Display for FlightDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "{}h {:02}m", self.hours(), self.minutes());
+    }
+}
+
+// e.g. "+0h 25m" for a delay, "-0h 10m" for an early arrival
+impl fmt::Display for SignedFlightDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.is_negative() { '-' } else { '+' };
+        return write!(f, "{}{}", sign, self.abs());
+    }
+}
+
+#[cfg(test)]
+mod test_flight_duration {
+    use super::*;
+
+    #[test]
+    fn test_constructors() {
+        assert_eq!(FlightDuration::from_hours_minutes(11, 5), FlightDuration::from_minutes(665));
+        assert_eq!(FlightDuration::from_hours(2).as_seconds(), 7200);
+        assert_eq!(SignedFlightDuration::from_hours_minutes(-1, -30), SignedFlightDuration::from_minutes(-90));
+    }
+
+    #[test]
+    fn test_to_string() {
+        assert_eq!(FlightDuration::from_hours_minutes(11, 5).to_string(), "11h 05m");
+        assert_eq!(FlightDuration::from_seconds(59).to_string(), "0h 00m");
+        assert_eq!(SignedFlightDuration::from_minutes(25).to_string(), "+0h 25m");
+        assert_eq!(SignedFlightDuration::from_minutes(-130).to_string(), "-2h 10m");
+    }
+
+    #[test]
+    fn test_sum_and_average() {
+        let legs = vec![FlightDuration::from_hours_minutes(2, 10), FlightDuration::from_hours_minutes(11, 5), FlightDuration::from_minutes(45)];
+        assert_eq!(legs.iter().sum::<FlightDuration>(), FlightDuration::from_hours(14));
+        assert_eq!(FlightDuration::average(&legs), Some(FlightDuration::from_hours_minutes(4, 40)));
+        assert_eq!(FlightDuration::average(&Vec::new()), None);
+
+        let delays = vec![SignedFlightDuration::from_minutes(-10), SignedFlightDuration::from_minutes(40)];
+        assert_eq!(delays.iter().sum::<SignedFlightDuration>(), SignedFlightDuration::from_minutes(30));
+        assert_eq!(SignedFlightDuration::average(&delays), Some(SignedFlightDuration::from_minutes(15)));
+    }
+
+    #[test]
+    fn test_signed_unsigned_conversion() {
+        let early = FlightDuration::from_hours(2).checked_sub(FlightDuration::from_hours_minutes(2, 15)).unwrap();
+        assert_eq!(early, SignedFlightDuration::from_minutes(-15));
+        assert_eq!(early.abs(), FlightDuration::from_minutes(15));
+        assert_eq!(early.to_unsigned(), None);
+        assert_eq!((-early).to_unsigned(), Some(FlightDuration::from_minutes(15)));
+        assert_eq!(SignedFlightDuration::try_from(TimeDelta::minutes(-15)), Ok(early));
+        assert_eq!(TimeDelta::try_from(early), Ok(TimeDelta::minutes(-15)));
+    }
+
+    #[test]
+    fn test_conversion_errors() {
+        assert_eq!(SignedFlightDuration::try_from(TimeDelta::milliseconds(1500)), Err(FlightDurationError::FractionalSeconds(TimeDelta::milliseconds(1500))));
+        assert_eq!(FlightDuration::from_seconds(u64::MAX).to_signed(), Err(FlightDurationError::OutOfRange));
+        assert_eq!(FlightDuration::from_seconds(u64::MAX).checked_sub(FlightDuration::from_seconds(1)), Err(FlightDurationError::OutOfRange));
+        assert_eq!(TimeDelta::try_from(SignedFlightDuration::from_seconds(i64::MAX)), Err(FlightDurationError::OutOfRange));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let longest = FlightDuration::from_seconds(u64::MAX);
+        assert_eq!(longest + FlightDuration::from_seconds(1), longest);
+        assert_eq!(longest.checked_add(FlightDuration::from_seconds(1)), Err(FlightDurationError::OutOfRange));
+        assert_eq!(FlightDuration::from_hours(u64::MAX / 10), longest);
+        assert_eq!(FlightDuration::checked_from_hours_minutes(u64::MAX / 10, 0), Err(FlightDurationError::OutOfRange));
+        assert_eq!(FlightDuration::checked_from_hours_minutes(11, 5), Ok(FlightDuration::from_minutes(665)));
+        assert_eq!([longest, longest].iter().sum::<FlightDuration>(), longest);
+        assert_eq!(FlightDuration::average(&[longest, longest]), Some(longest));
+
+        let earliest = SignedFlightDuration::from_seconds(i64::MIN);
+        let latest = SignedFlightDuration::from_seconds(i64::MAX);
+        assert_eq!(-earliest, latest);
+        assert_eq!(earliest.checked_neg(), Err(FlightDurationError::OutOfRange));
+        assert_eq!(earliest - SignedFlightDuration::from_seconds(1), earliest);
+        assert_eq!(earliest.checked_sub(SignedFlightDuration::from_seconds(1)), Err(FlightDurationError::OutOfRange));
+        assert_eq!(latest.checked_add(SignedFlightDuration::from_seconds(1)), Err(FlightDurationError::OutOfRange));
+        assert_eq!(SignedFlightDuration::from_minutes(i64::MIN), earliest);
+        assert_eq!(SignedFlightDuration::checked_from_hours_minutes(i64::MAX, 0), Err(FlightDurationError::OutOfRange));
+        assert_eq!([latest, latest].iter().sum::<SignedFlightDuration>(), latest);
+        assert_eq!(SignedFlightDuration::average(&[latest, latest, earliest]), Some(SignedFlightDuration::from_seconds(i64::MAX / 3)));
+    }
+}
@@ -0,0 +1,176 @@
+use std::fmt;
+use std::iter::Sum;
+use std::ops::Add;
+
+use crate::domain::airport::Coordinates;
+
+pub const MEAN_EARTH_RADIUS_M: f64 = 6_371_008.8; // IUGG mean radius
+pub const WGS84_A: f64 = 6_378_137.0; // semi-major axis, unit: m
+pub const WGS84_F: f64 = 1.0 / 298.257_223_563; // flattening
+
+const METERS_PER_STATUTE_MILE: f64 = 1609.344;
+const METERS_PER_NAUTICAL_MILE: f64 = 1852.0;
+
+// Haversine treats the earth as a sphere (up to ~0.5% off), Vincenty uses the WGS-84 ellipsoid (sub-millimetre)
+// but does not converge for nearly antipodal points. Karney's algorithm, which always converges, is out of scope.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum DistanceMethod {
+    Haversine,
+    Vincenty
+}
+
+#[derive(PartialEq, PartialOrd, Debug, Clone, Copy, Default)]
+pub struct Distance {
+    meters: f64
+}
+
+impl Distance {
+    pub fn from_meters(meters: f64) -> Self {
+        return Distance { meters };
+    }
+
+    pub fn from_km(km: f64) -> Self {
+        return Distance { meters: km * 1000.0 };
+    }
+
+    pub fn as_meters(&self) -> f64 {
+        return self.meters;
+    }
+
+    pub fn as_km(&self) -> f64 {
+        return self.meters / 1000.0;
+    }
+
+    pub fn as_statute_miles(&self) -> f64 {
+        return self.meters / METERS_PER_STATUTE_MILE;
+    }
+
+    pub fn as_nautical_miles(&self) -> f64 {
+        return self.meters / METERS_PER_NAUTICAL_MILE;
+    }
+}
+
+impl Add for Distance {
+    type Output = Distance;
+
+    fn add(self, rhs: Distance) -> Distance {
+        return Distance { meters: self.meters + rhs.meters };
+    }
+}
+
+impl Sum for Distance {
+    fn sum<I: Iterator<Item = Distance>>(iter: I) -> Self {
+        return iter.fold(Distance::default(), |total, d| total + d);
+    }
+}
+
+// e.g. "5555 km / 3452 mi / 2999 nm"
+impl fmt::Display for Distance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "{:.0} km / {:.0} mi / {:.0} nm", self.as_km(), self.as_statute_miles(), self.as_nautical_miles());
+    }
+}
+
+pub fn haversine(from: &Coordinates, to: &Coordinates) -> Distance {
+    let (lat1, lat2) = (from.latitude.to_radians(), to.latitude.to_radians());
+    let d_lat = lat2 - lat1;
+    let d_lon = (to.longitude - from.longitude).to_radians();
+    let h = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lon / 2.0).sin().powi(2);
+    let central_angle = 2.0 * h.sqrt().min(1.0).asin();
+    return Distance { meters: MEAN_EARTH_RADIUS_M * central_angle };
+}
+
+// Vincenty's inverse formula, None when it does not converge (nearly antipodal points)
+pub fn vincenty(from: &Coordinates, to: &Coordinates) -> Option<Distance> {
+    let b = (1.0 - WGS84_F) * WGS84_A;
+    let l = (to.longitude - from.longitude).to_radians();
+    let u1 = ((1.0 - WGS84_F) * from.latitude.to_radians().tan()).atan();
+    let u2 = ((1.0 - WGS84_F) * to.latitude.to_radians().tan()).atan();
+    let (sin_u1, cos_u1) = u1.sin_cos();
+    let (sin_u2, cos_u2) = u2.sin_cos();
+
+    let mut lambda = l;
+    for _ in 0..200 {
+        let (sin_lambda, cos_lambda) = lambda.sin_cos();
+        let sin_sigma = ((cos_u2 * sin_lambda).powi(2) + (cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda).powi(2)).sqrt();
+        if sin_sigma == 0.0 {
+            return Some(Distance::default()); // same point
+        }
+        let cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+        let sigma = sin_sigma.atan2(cos_sigma);
+        let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+        let cos_sq_alpha = 1.0 - sin_alpha * sin_alpha;
+        // on the equator cos_sq_alpha is zero and the term is not used
+        let cos_2sigma_m = if cos_sq_alpha == 0.0 { 0.0 } else { cos_sigma - 2.0 * sin_u1 * sin_u2 / cos_sq_alpha };
+        let c = WGS84_F / 16.0 * cos_sq_alpha * (4.0 + WGS84_F * (4.0 - 3.0 * cos_sq_alpha));
+        let previous = lambda;
+        lambda = l + (1.0 - c) * WGS84_F * sin_alpha
+            * (sigma + c * sin_sigma * (cos_2sigma_m + c * cos_sigma * (-1.0 + 2.0 * cos_2sigma_m * cos_2sigma_m)));
+        if (lambda - previous).abs() < 1e-12 {
+            let u_sq = cos_sq_alpha * (WGS84_A * WGS84_A - b * b) / (b * b);
+            let big_a = 1.0 + u_sq / 16384.0 * (4096.0 + u_sq * (-768.0 + u_sq * (320.0 - 175.0 * u_sq)));
+            let big_b = u_sq / 1024.0 * (256.0 + u_sq * (-128.0 + u_sq * (74.0 - 47.0 * u_sq)));
+            let delta_sigma = big_b * sin_sigma * (cos_2sigma_m + big_b / 4.0
+                * (cos_sigma * (-1.0 + 2.0 * cos_2sigma_m * cos_2sigma_m)
+                    - big_b / 6.0 * cos_2sigma_m * (-3.0 + 4.0 * sin_sigma * sin_sigma) * (-3.0 + 4.0 * cos_2sigma_m * cos_2sigma_m)));
+            return Some(Distance { meters: b * big_a * (sigma - delta_sigma) });
+        }
+    }
+    return None;
+}
+
+// None where Vincenty does not converge; there is no silent fallback, callers pick Haversine themselves
+pub fn distance(from: &Coordinates, to: &Coordinates, method: DistanceMethod) -> Option<Distance> {
+    return match method {
+        DistanceMethod::Haversine => Some(haversine(from, to)),
+        DistanceMethod::Vincenty => vincenty(from, to),
+    };
+}
+
+#[cfg(test)]
+mod test_geodesic {
+    use super::*;
+
+    fn dms(degrees: f64, minutes: f64, seconds: f64) -> f64 {
+        return degrees.signum() * (degrees.abs() + minutes / 60.0 + seconds / 3600.0);
+    }
+
+    #[test]
+    fn test_vincenty_reference_line() {
+        // Flinders Peak to Buninyong, the worked example of Vincenty (1975)
+        let flinders = Coordinates::new(dms(-37.0, 57.0, 3.72030), dms(144.0, 25.0, 29.52440)).unwrap();
+        let buninyong = Coordinates::new(dms(-37.0, 39.0, 10.15610), dms(143.0, 55.0, 35.38390)).unwrap();
+        let d = vincenty(&flinders, &buninyong).unwrap();
+        assert!((d.as_meters() - 54_972.271).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_haversine_and_vincenty_agree_roughly() {
+        let jfk = Coordinates::new(40.6398, -73.7789).unwrap();
+        let lhr = Coordinates::new(51.4706, -0.461941).unwrap();
+        let h = haversine(&jfk, &lhr);
+        let v = vincenty(&jfk, &lhr).unwrap();
+        assert!((h.as_km() - 5539.6).abs() < 1.0);
+        assert!((h.as_km() - v.as_km()).abs() / v.as_km() < 0.005);
+        assert_eq!(haversine(&jfk, &jfk), Distance::default());
+        assert_eq!(vincenty(&jfk, &jfk), Some(Distance::default()));
+    }
+
+    #[test]
+    fn test_nearly_antipodal_does_not_converge() {
+        let a = Coordinates::new(0.0, 0.0).unwrap();
+        let b = Coordinates::new(0.5, 179.7).unwrap();
+        assert_eq!(vincenty(&a, &b), None);
+        assert_eq!(distance(&a, &b, DistanceMethod::Vincenty), None);
+        assert_eq!(distance(&a, &b, DistanceMethod::Haversine), Some(haversine(&a, &b)));
+    }
+
+    #[test]
+    fn test_units() {
+        let d = Distance::from_km(1852.0);
+        assert!((d.as_nautical_miles() - 1000.0).abs() < 1e-9);
+        assert!((d.as_statute_miles() - 1150.779).abs() < 1e-3);
+        assert_eq!(d.to_string(), "1852 km / 1151 mi / 1000 nm");
+        assert_eq!(vec![Distance::from_km(1.0), Distance::from_meters(500.0)].into_iter().sum::<Distance>(), Distance::from_meters(1500.0));
+    }
+}
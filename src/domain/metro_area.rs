@@ -0,0 +1,116 @@
+// IATA metropolitan area (city) codes that group the airports serving one city, e.g. LON for LHR, LGW, STN, LCY, LTN and SEN
+
+use std::collections::BTreeSet;
+
+use crate::domain::{airport::Airport, airport_registry::AirportRegistry};
+
+#[derive(PartialEq, Eq, Debug)]
+pub struct MetroArea {
+    code: &'static str,
+    name: &'static str,
+    airports: &'static [&'static str] // IATA airport codes, main airport first
+}
+
+// a city code may equal the code of one of its airports (SHA, BKK), lookups then prefer the metro area
+pub const METRO_AREAS: &[MetroArea] = &[
+    MetroArea { code: "BJS", name: "Beijing", airports: &["PEK", "PKX"] },
+    MetroArea { code: "BKK", name: "Bangkok", airports: &["BKK", "DMK"] },
+    MetroArea { code: "CHI", name: "Chicago", airports: &["ORD", "MDW"] },
+    MetroArea { code: "CTU", name: "Chengdu", airports: &["TFU", "CTU"] },
+    MetroArea { code: "IST", name: "Istanbul", airports: &["IST", "SAW"] },
+    MetroArea { code: "LON", name: "London", airports: &["LHR", "LGW", "STN", "LCY", "LTN", "SEN"] },
+    MetroArea { code: "MIL", name: "Milan", airports: &["MXP", "LIN", "BGY"] },
+    MetroArea { code: "MOW", name: "Moscow", airports: &["SVO", "DME", "VKO"] },
+    MetroArea { code: "NYC", name: "New York", airports: &["JFK", "EWR", "LGA"] },
+    MetroArea { code: "OSA", name: "Osaka", airports: &["KIX", "ITM", "UKB"] },
+    MetroArea { code: "PAR", name: "Paris", airports: &["CDG", "ORY", "BVA"] },
+    MetroArea { code: "ROM", name: "Rome", airports: &["FCO", "CIA"] },
+    MetroArea { code: "SAO", name: "São Paulo", airports: &["GRU", "CGH", "VCP"] },
+    MetroArea { code: "SEL", name: "Seoul", airports: &["ICN", "GMP"] },
+    MetroArea { code: "SHA", name: "Shanghai", airports: &["PVG", "SHA"] },
+    MetroArea { code: "STO", name: "Stockholm", airports: &["ARN", "BMA", "NYO"] },
+    MetroArea { code: "TYO", name: "Tokyo", airports: &["HND", "NRT"] },
+    MetroArea { code: "WAS", name: "Washington", airports: &["IAD", "DCA", "BWI"] },
+    MetroArea { code: "YTO", name: "Toronto", airports: &["YYZ", "YTZ"] },
+];
+
+impl MetroArea {
+    pub fn find(code: &str) -> Option<&'static MetroArea> {
+        let code = code.trim().to_uppercase();
+        return METRO_AREAS.iter().find(|metro| metro.code == code);
+    }
+
+    pub fn of_airport(airport: &Airport) -> Option<&'static MetroArea> {
+        return METRO_AREAS.iter().find(|metro| metro.contains(airport));
+    }
+
+    pub fn get_code(&self) -> &str {
+        return self.code;
+    }
+
+    pub fn get_name(&self) -> &str {
+        return self.name;
+    }
+
+    pub fn get_airport_codes(&self) -> &[&'static str] {
+        return self.airports;
+    }
+
+    pub fn contains(&self, airport: &Airport) -> bool {
+        return self.airports.contains(&airport.get_iata_code());
+    }
+}
+
+// the city an airport counts towards in statistics: the metro code when it has one, its own IATA code otherwise
+pub fn city_code(airport: &Airport) -> String {
+    return match MetroArea::of_airport(airport) {
+        Some(metro) => metro.code.to_string(),
+        None => airport.get_iata_code().to_string(),
+    };
+}
+
+// candidates for a code from a ticket: every known airport of a metro area, or the single airport of that code
+pub fn resolve_city_code<'a>(registry: &'a AirportRegistry, code: &str) -> Vec<&'a Airport> {
+    if let Some(metro) = MetroArea::find(code) {
+        return metro.airports.iter().filter_map(|code| registry.get_by_iata(code)).collect();
+    }
+    return registry.get_by_iata(code).into_iter().collect();
+}
+
+// distinct cities among the airports, London counts once whether it was LHR or LGW
+pub fn visited_cities<'a, I: IntoIterator<Item = &'a Airport>>(airports: I) -> BTreeSet<String> {
+    return airports.into_iter().map(city_code).collect();
+}
+
+#[cfg(test)]
+mod test_metro_area {
+    use super::*;
+
+    fn codes(airports: Vec<&Airport>) -> Vec<&str> {
+        return airports.into_iter().map(|airport| airport.get_iata_code()).collect();
+    }
+
+    #[test]
+    fn test_resolve_city_code() {
+        let registry = AirportRegistry::bundled();
+        assert_eq!(codes(resolve_city_code(&registry, "LON")), vec!["LHR", "LGW", "STN", "LCY", "LTN", "SEN"]);
+        assert_eq!(codes(resolve_city_code(&registry, "nyc")), vec!["JFK", "EWR", "LGA"]);
+        assert_eq!(codes(resolve_city_code(&registry, "TYO")), vec!["HND", "NRT"]);
+        assert_eq!(codes(resolve_city_code(&registry, "BJS")), vec!["PEK", "PKX"]);
+        // only airports present in the registry are offered
+        assert_eq!(codes(resolve_city_code(&registry, "PAR")), vec!["CDG", "ORY"]);
+        assert_eq!(codes(resolve_city_code(&registry, "SHA")), vec!["PVG", "SHA"]);
+        assert_eq!(codes(resolve_city_code(&registry, "FRA")), vec!["FRA"]);
+        assert!(resolve_city_code(&registry, "XXX").is_empty());
+    }
+
+    #[test]
+    fn test_visited_cities() {
+        let registry = AirportRegistry::bundled();
+        let trips = ["PEK", "LHR", "LGW", "PKX", "FRA", "STN", "JFK", "EWR"].map(|code| registry.get_by_iata(code).unwrap());
+        let cities = visited_cities(trips);
+        assert_eq!(cities.into_iter().collect::<Vec<String>>(), vec!["BJS", "FRA", "LON", "NYC"]);
+        assert_eq!(MetroArea::of_airport(registry.get("LCY").unwrap()).unwrap().get_name(), "London");
+        assert_eq!(MetroArea::find("lon").unwrap().get_airport_codes().len(), 6);
+    }
+}
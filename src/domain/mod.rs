@@ -1,8 +1,22 @@
 // header file of domains, no implementation
 
-mod aircraft;
-mod airport;
-mod airline;
-mod time_point;
-mod flight;
-mod ticket;
+pub mod aircraft;
+pub mod airport;
+pub mod airport_code;
+pub mod country;
+pub mod airport_registry;
+pub mod airport_search;
+pub mod airside;
+pub mod metro_area;
+pub mod airline;
+pub mod airline_code;
+pub mod geodesic;
+pub mod time_point;
+pub mod time_format;
+pub mod flight_duration;
+pub mod clock;
+pub mod flight;
+pub mod ticket;
+
+#[cfg(test)]
+pub(crate) mod test_support;
@@ -0,0 +1,17 @@
+// fixtures shared by the test modules of the domain
+
+use crate::domain::{aircraft::Aircraft, airline::Airline, airport::Airport, country::Country, time_point::TimePoint};
+
+// an airport in the country its ICAO code is allocated to, named after its city
+pub(crate) fn airport(iata_code: &str, icao_code: &str, city: &str, latitude: f64, longitude: f64, elevation: i32) -> Airport {
+    return Airport::new(iata_code.into(), icao_code.into(), format!("{} Airport", city), city.into(), "".into(), Country::from_icao_airport_code(icao_code).unwrap(), latitude, longitude, elevation).unwrap();
+}
+
+// Air China's 747-8 B-2485
+pub(crate) fn get_test_aircraft_and_airline() -> (Aircraft, Airline) {
+    let aircraft = Aircraft::new("Air China".into(), "B-2485".into(), "Boeing".into(), "747".into(), "747-89L".into(),
+        TimePoint::new_date(2014, 9, 29, 8 * 60).unwrap(), "F12C54Y299".into()).unwrap();
+    let airline = Airline::new("CA".into(), "CCA".into(), "AIR CHINA".into(), "Air China".into(), Country::parse("China").unwrap(),
+        "Star Alliance".into(), "PhoenixMiles".into(), "999".into()).unwrap();
+    return (aircraft, airline);
+}
@@ -1,7 +1,8 @@
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 
-use crate::domain::flight::Flight;
+use crate::domain::{clock::Clock, flight::Flight, flight_duration::FlightDuration, metro_area};
 
+#[allow(dead_code)] // no way to record a ticket yet, only the flights are read so far
 pub struct Ticket {
     etkt: String,
     pnr: String,
@@ -13,3 +14,34 @@ pub struct Ticket {
     identification: String, // e.g. passport number
     seat_number: Vec<String>, // 0: 1st flight...
 }
+
+impl Ticket {
+    // ground time between landing of one flight and takeoff of the next, as actually flown, one per connection;
+    // None when unknown or when the next flight is recorded as leaving before the previous one landed
+    pub fn layovers(&self) -> Vec<Option<FlightDuration>> {
+        return self.flights.windows(2)
+            .map(|pair| {
+                let arrival = pair[0].get_arrival()?;
+                let departure = pair[1].get_departure()?;
+                if !arrival.actual.is_exact() || !departure.actual.is_exact() {
+                    return None;
+                }
+                return (&departure.actual - &arrival.actual).to_unsigned();
+            })
+            .collect();
+    }
+
+    // a trip stays upcoming until its last flight has taken off
+    pub fn is_upcoming(&self, clock: &dyn Clock) -> bool {
+        return self.flights.iter().any(|flight| flight.is_upcoming(clock));
+    }
+
+    pub fn total_block_time(&self) -> FlightDuration {
+        return self.flights.iter().filter_map(|flight| flight.block_time()).sum();
+    }
+
+    // city codes touched by this trip, a metro area counts once, see metro_area::visited_cities
+    pub fn visited_cities(&self) -> BTreeSet<String> {
+        return metro_area::visited_cities(self.flights.iter().flat_map(|flight| flight.get_airports()));
+    }
+}
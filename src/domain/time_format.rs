@@ -0,0 +1,171 @@
+// itinerary-style formatting of TimePoint, next to the plain `Display` in time_point.rs
+
+use chrono::DateTime;
+use chrono_tz::Tz;
+
+use crate::domain::{clock::Clock, time_point::{Precision, TimePoint}};
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Locale {
+    English,
+    Chinese
+}
+
+const ENGLISH_MONTHS: [&str; 12] = ["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"];
+
+// local wall clock with the zone abbreviation, e.g. "14:05 CST"; a fixed offset prints as "14:05 UTC+08:00"
+pub fn format_clock(time: &TimePoint) -> String {
+    if !time.is_exact() {
+        return time.to_string();
+    }
+    return format!("{:02}:{:02} {}", time.hour, time.minute, zone_label(time));
+}
+
+// arrival clock with a day marker against the departure's local date, e.g. "06:10+1 BST"
+pub fn format_arrival(arrival: &TimePoint, departure: &TimePoint) -> String {
+    if !arrival.is_exact() {
+        return arrival.to_string();
+    }
+    let days = local_day_number(arrival) - local_day_number(departure);
+    let marker = if days == 0 { String::new() } else { format!("{:+}", days) };
+    return format!("{:02}:{:02}{} {}", arrival.hour, arrival.minute, marker, zone_label(arrival));
+}
+
+// e.g. "1 October 2025" or "2025年10月1日", only as precise as the value is known
+pub fn format_date(time: &TimePoint, locale: Locale) -> String {
+    let month_name = ENGLISH_MONTHS[(time.month - 1).clamp(0, 11) as usize];
+    return match (locale, time.precision) {
+        (Locale::English, Precision::Year) => format!("{}", time.year),
+        (Locale::English, Precision::Month) => format!("{} {}", month_name, time.year),
+        (Locale::English, _) => format!("{} {} {}", time.day, month_name, time.year),
+        (Locale::Chinese, Precision::Year) => format!("{}年", time.year),
+        (Locale::Chinese, Precision::Month) => format!("{}年{}月", time.year, time.month),
+        (Locale::Chinese, _) => format!("{}年{}月{}日", time.year, time.month, time.day),
+    };
+}
+
+// e.g. "in 3 days" or "2 hours ago", measured against `now`
+pub fn format_relative_to(time: &TimePoint, now: &TimePoint, locale: Locale) -> String {
+    let seconds = (time - now).as_seconds();
+    let magnitude = seconds.unsigned_abs();
+    let (amount, unit) = match magnitude {
+        0..60 => return match locale {
+            Locale::English => "just now".to_string(),
+            Locale::Chinese => "刚刚".to_string(),
+        },
+        60..3600 => (magnitude / 60, RelativeUnit::Minute),
+        3600..86400 => (magnitude / 3600, RelativeUnit::Hour),
+        86400..2592000 => (magnitude / 86400, RelativeUnit::Day),
+        2592000..31536000 => (magnitude / 2592000, RelativeUnit::Month),
+        _ => (magnitude / 31536000, RelativeUnit::Year),
+    };
+    return match locale {
+        Locale::English => {
+            let plural = if amount == 1 { "" } else { "s" };
+            if seconds > 0 {
+                format!("in {} {}{}", amount, unit.english(), plural)
+            } else {
+                format!("{} {}{} ago", amount, unit.english(), plural)
+            }
+        },
+        Locale::Chinese => format!("{}{}{}", amount, unit.chinese(), if seconds > 0 { "后" } else { "前" }),
+    };
+}
+
+pub fn format_relative(time: &TimePoint, clock: &dyn Clock, locale: Locale) -> String {
+    return format_relative_to(time, &clock.now(), locale);
+}
+
+fn zone_label(time: &TimePoint) -> String {
+    if let Ok(local) = DateTime::<Tz>::try_from(time) {
+        return local.format("%Z").to_string();
+    }
+    return format!("UTC{}", time.format_offset());
+}
+
+// calendar date as a plain day count, so local dates in different offsets can be compared
+fn local_day_number(time: &TimePoint) -> i64 {
+    return TimePoint::days_from_epoch(time.year, time.month, time.day);
+}
+
+enum RelativeUnit {
+    Minute,
+    Hour,
+    Day,
+    Month,
+    Year
+}
+
+impl RelativeUnit {
+    fn english(&self) -> &'static str {
+        return match self {
+            RelativeUnit::Minute => "minute",
+            RelativeUnit::Hour => "hour",
+            RelativeUnit::Day => "day",
+            RelativeUnit::Month => "month",
+            RelativeUnit::Year => "year",
+        };
+    }
+
+    fn chinese(&self) -> &'static str {
+        return match self {
+            RelativeUnit::Minute => "分钟",
+            RelativeUnit::Hour => "小时",
+            RelativeUnit::Day => "天",
+            RelativeUnit::Month => "个月",
+            RelativeUnit::Year => "年",
+        };
+    }
+}
+
+#[cfg(test)]
+mod test_time_format {
+    use super::*;
+    use crate::domain::{clock::FixedClock, flight_duration::FlightDuration};
+
+    #[test]
+    fn test_format_clock() {
+        let pek = TimePoint::new_in_zone(2025, 10, 1, 14, 5, 0, chrono_tz::Asia::Shanghai).unwrap();
+        assert_eq!(format_clock(&pek), "14:05 CST");
+        let lhr = TimePoint::new_in_zone(2025, 7, 1, 6, 10, 0, chrono_tz::Europe::London).unwrap();
+        assert_eq!(format_clock(&lhr), "06:10 BST");
+        let fixed = TimePoint::new(2025, 10, 1, 14, 5, 0, 5 * 60 + 45).unwrap();
+        assert_eq!(format_clock(&fixed), "14:05 UTC+05:45");
+    }
+
+    #[test]
+    fn test_format_arrival_day_marker() {
+        let departure = TimePoint::new_in_zone(2025, 7, 1, 13, 0, 0, chrono_tz::Asia::Shanghai).unwrap();
+        let arrival = TimePoint::new_in_zone(2025, 7, 2, 6, 10, 0, chrono_tz::Europe::London).unwrap();
+        assert_eq!(format_arrival(&arrival, &departure), "06:10+1 BST");
+        let same_day = departure.checked_add(FlightDuration::from_hours(2)).unwrap();
+        assert_eq!(format_arrival(&same_day, &departure), "15:00 CST");
+        // CXI -> HNL lands the day before
+        let cxi = TimePoint::new(2024, 5, 2, 8, 0, 0, 14 * 60).unwrap();
+        let hnl = TimePoint::new(2024, 5, 1, 11, 0, 0, -10 * 60).unwrap();
+        assert_eq!(format_arrival(&hnl, &cxi), "11:00-1 UTC-10:00");
+    }
+
+    #[test]
+    fn test_format_date() {
+        let tp = TimePoint::new(2025, 10, 1, 10, 32, 17, 8 * 60).unwrap();
+        assert_eq!(format_date(&tp, Locale::English), "1 October 2025");
+        assert_eq!(format_date(&tp, Locale::Chinese), "2025年10月1日");
+        let march = TimePoint::new_month(2009, 3, 8 * 60).unwrap();
+        assert_eq!(format_date(&march, Locale::English), "March 2009");
+        assert_eq!(format_date(&march, Locale::Chinese), "2009年3月");
+    }
+
+    #[test]
+    fn test_format_relative() {
+        let now = TimePoint::new(2025, 10, 18, 12, 0, 0, 8 * 60).unwrap();
+        let clock = FixedClock::new(now.clone());
+        assert_eq!(format_relative(&now.checked_add(FlightDuration::from_hours(72)).unwrap(), &clock, Locale::English), "in 3 days");
+        assert_eq!(format_relative(&now.checked_sub(FlightDuration::from_hours(2)).unwrap(), &clock, Locale::English), "2 hours ago");
+        assert_eq!(format_relative(&now.checked_sub(FlightDuration::from_minutes(1)).unwrap(), &clock, Locale::English), "1 minute ago");
+        assert_eq!(format_relative(&now.checked_add(FlightDuration::from_seconds(20)).unwrap(), &clock, Locale::English), "just now");
+        assert_eq!(format_relative(&now.checked_add(FlightDuration::from_hours(72)).unwrap(), &clock, Locale::Chinese), "3天后");
+        assert_eq!(format_relative(&now.checked_sub(FlightDuration::from_hours(2)).unwrap(), &clock, Locale::Chinese), "2小时前");
+        assert_eq!(format_relative_to(&now.checked_sub(FlightDuration::from_hours(24 * 400)).unwrap(), &now, Locale::English), "1 year ago");
+    }
+}
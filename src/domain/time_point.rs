@@ -1,6 +1,31 @@
-use chrono::{Datelike, Local, Offset, Timelike};
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+use std::str::FromStr;
 
-#[derive(PartialEq, Eq, Debug, Clone)]
+use chrono::{DateTime, Datelike, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, Offset, TimeDelta, TimeZone, Timelike, Utc};
+use chrono_tz::Tz;
+
+use crate::domain::flight_duration::{FlightDuration, SignedFlightDuration};
+
+// real-world civil offsets run from UTC-12:00 (Baker Island) to UTC+14:00 (Line Islands)
+pub const MIN_UTC_OFFSET: i32 = -12 * 60;
+pub const MAX_UTC_OFFSET: i32 = 14 * 60;
+
+// how much of a TimePoint is actually known, from coarse to fine
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+pub enum Precision {
+    Year,
+    Month,
+    Day,
+    Minute,
+    Second
+}
+
+// equality, ordering and hashing follow the absolute instant (the start of the period when imprecise)
+// and the precision, not the wall-clock fields
+#[derive(Debug, Clone)]
 pub struct TimePoint {
     pub year: i32,
     pub month: i32,
@@ -8,220 +33,343 @@ pub struct TimePoint {
     pub hour: i32,
     pub minute: i32,
     pub second: i32,
-    pub utc_offset: i32
+    pub utc_offset: i32, // unit: minutes east of UTC, e.g. 345 for UTC+05:45
+    pub zone: Option<Tz>, // IANA zone the offset was resolved from, None for a fixed offset
+    pub precision: Precision // fields finer than this are unknown and held at their first value
 }
 
 impl TimePoint {
-    pub fn new(year: i32, month: i32, day: i32, hour: i32, minute: i32, second: i32, utc_offset: i32) -> Self {
-        if TimePoint::valid_timepoint(year, month, day, hour, minute, second, utc_offset) == false {
-            return TimePoint { year: 0, month: -1, day: -1, hour: -1, minute: -1, second: -1, utc_offset: 99 };
+    pub fn new(year: i32, month: i32, day: i32, hour: i32, minute: i32, second: i32, utc_offset: i32) -> Result<Self, TimePointError> {
+        TimePoint::validate_fields(year, month, day, hour, minute, second, utc_offset)?;
+        return Ok(TimePoint { year, month, day, hour, minute, second, utc_offset, zone: None, precision: Precision::Second });
+    }
+
+    // e.g. a historic flight where only the date is remembered
+    pub fn new_date(year: i32, month: i32, day: i32, utc_offset: i32) -> Result<Self, TimePointError> {
+        return TimePoint::new(year, month, day, 0, 0, 0, utc_offset)?.with_precision(Precision::Day);
+    }
+
+    // e.g. "sometime in March 2009"
+    pub fn new_month(year: i32, month: i32, utc_offset: i32) -> Result<Self, TimePointError> {
+        return TimePoint::new(year, month, 1, 0, 0, 0, utc_offset)?.with_precision(Precision::Month);
+    }
+
+    pub fn new_year(year: i32, utc_offset: i32) -> Result<Self, TimePointError> {
+        return TimePoint::new(year, 1, 1, 0, 0, 0, utc_offset)?.with_precision(Precision::Year);
+    }
+
+    // drops everything finer than `precision`; it never claims more precision than already known.
+    // In a zone the truncated wall clock is resolved again and rejected inside a DST gap or overlap,
+    // see `with_precision_with` to pick a policy.
+    pub fn with_precision(self, precision: Precision) -> Result<Self, TimePointError> {
+        return self.with_precision_with(precision, DstPolicy::Reject);
+    }
+
+    pub fn with_precision_with(mut self, precision: Precision, policy: DstPolicy) -> Result<Self, TimePointError> {
+        let precision = precision.min(self.precision);
+        if precision == self.precision {
+            return Ok(self);
+        }
+        if precision < Precision::Second {
+            self.second = 0;
+        }
+        if precision < Precision::Minute {
+            self.hour = 0;
+            self.minute = 0;
+        }
+        if precision < Precision::Day {
+            self.day = 1;
+        }
+        if precision < Precision::Month {
+            self.month = 1;
         }
-        return TimePoint { year, month, day, hour, minute, second, utc_offset };
+        self.precision = precision;
+        self.resolve_wall_clock(policy)?;
+        return Ok(self);
     }
 
-    pub fn to_string(&self) -> String {
-        return format!("{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC{:+02}",
-            self.year, self.month, self.day, self.hour, self.minute, self.second, self.utc_offset
+    // exact enough to take part in durations and block times
+    pub fn is_exact(&self) -> bool {
+        return self.precision >= Precision::Minute;
+    }
+
+    // resolves the UTC offset of a local wall-clock time in an IANA zone, DST included.
+    // Times inside a DST gap or overlap are rejected, see `new_in_zone_with` to pick a policy.
+    pub fn new_in_zone(year: i32, month: i32, day: i32, hour: i32, minute: i32, second: i32, zone: Tz) -> Result<Self, TimePointError> {
+        return TimePoint::new_in_zone_with(year, month, day, hour, minute, second, zone, DstPolicy::Reject);
+    }
+
+    #[allow(clippy::too_many_arguments)] // date and time spelled out like TimePoint::new
+    pub fn new_in_zone_with(year: i32, month: i32, day: i32, hour: i32, minute: i32, second: i32, zone: Tz, policy: DstPolicy) -> Result<Self, TimePointError> {
+        return match (TimePoint::resolve_in_zone(year, month, day, hour, minute, second, zone)?, policy) {
+            (LocalTimeResolution::Unique(tp), _) => Ok(tp),
+            (LocalTimeResolution::Ambiguous(earlier, _), DstPolicy::Earlier) => Ok(earlier),
+            (LocalTimeResolution::Ambiguous(_, later), DstPolicy::Later | DstPolicy::ShiftForward) => Ok(later),
+            (LocalTimeResolution::Ambiguous(earlier, later), DstPolicy::Reject) =>
+                Err(TimePointError::AmbiguousLocalTime { zone, earlier_offset: earlier.utc_offset, later_offset: later.utc_offset }),
+            (LocalTimeResolution::NonExistent, DstPolicy::ShiftForward) => {
+                // read the wall clock with the offset in force before the gap, which lands after it
+                let naive = TimePoint::naive_local(year, month, day, hour, minute, second).ok_or(TimePointError::OutOfRange)?;
+                let offset_before = TimePoint::offset_before_gap(zone, naive).ok_or(TimePointError::NonExistentLocalTime { zone })?;
+                let instant = (naive - TimeDelta::seconds(offset_before as i64)).and_utc().timestamp();
+                TimePoint::from_instant(instant, 0, Some(zone), Precision::Second)
+            },
+            (LocalTimeResolution::NonExistent, _) => Err(TimePointError::NonExistentLocalTime { zone }),
+        };
+    }
+
+    // every instant a local wall-clock time can stand for in a zone: one, two around a DST fall-back, none in a gap
+    pub fn resolve_in_zone(year: i32, month: i32, day: i32, hour: i32, minute: i32, second: i32, zone: Tz) -> Result<LocalTimeResolution, TimePointError> {
+        TimePoint::validate_date_time(year, month, day, hour, minute, second)?;
+        let at_offset = |offset: &<Tz as TimeZone>::Offset| TimePoint {
+            year, month, day, hour, minute, second,
+            utc_offset: offset.fix().local_minus_utc() / 60,
+            zone: Some(zone),
+            precision: Precision::Second
+        };
+        let naive = TimePoint::naive_local(year, month, day, hour, minute, second).ok_or(TimePointError::OutOfRange)?;
+        return Ok(match zone.from_local_datetime(&naive) {
+            LocalResult::Single(dt) => LocalTimeResolution::Unique(at_offset(dt.offset())),
+            LocalResult::Ambiguous(earlier, later) => LocalTimeResolution::Ambiguous(at_offset(earlier.offset()), at_offset(later.offset())),
+            LocalResult::None => LocalTimeResolution::NonExistent,
+        });
+    }
+
+    // the fields are public, so values edited after construction can be re-checked here
+    pub fn validate(&self) -> Result<(), TimePointError> {
+        return TimePoint::validate_fields(self.year, self.month, self.day, self.hour, self.minute, self.second, self.utc_offset);
+    }
+
+    // looser wall-clock forms as pasted from itineraries, e.g. "2025-10-01 10:32", read in the airport's zone
+    pub fn parse_local(text: &str, zone: Tz) -> Result<Self, TimePointError> {
+        const FORMATS: [&str; 6] = ["%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M", "%Y-%m-%dT%H:%M:%S", "%Y-%m-%dT%H:%M", "%Y/%m/%d %H:%M:%S", "%Y/%m/%d %H:%M"];
+        let text = text.trim();
+        let (local, format) = FORMATS.iter()
+            .find_map(|format| NaiveDateTime::parse_from_str(text, format).ok().map(|local| (local, format)))
+            .ok_or_else(|| TimePointError::InvalidFormat(text.to_string()))?;
+        let precision = if format.ends_with("%S") { Precision::Second } else { Precision::Minute };
+        return TimePoint::new_in_zone(local.year(), local.month() as i32, local.day() as i32,
+            local.hour() as i32, local.minute() as i32, local.second() as i32, zone)?.with_precision(precision);
+    }
+
+    // e.g. "2025-10-01T10:32:17+08:00", the counterpart of `FromStr`
+    pub fn to_rfc3339(&self) -> String {
+        return format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}{}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second, self.format_offset()
         );
     }
 
-    pub fn calc_bias_with(&self, tp2: &TimePoint) -> i64 {
-        let total_seconds1 = self.to_seconds();
-        let total_seconds2 = tp2.to_seconds();
-        return total_seconds1 - total_seconds2;
+    // e.g. "+05:45"
+    pub fn format_offset(&self) -> String {
+        let sign = if self.utc_offset < 0 { '-' } else { '+' };
+        return format!("{}{:02}:{:02}", sign, self.utc_offset.abs() / 60, self.utc_offset.abs() % 60);
+    }
+
+    pub fn calc_duration_with(&self, timepoint2: &TimePoint) -> FlightDuration {
+        return (self - timepoint2).abs();
+    }
+
+    // exact-duration arithmetic: the instant moves, the offset (or zone) is kept, and a coarse value is
+    // truncated to its precision again, so a date plus 36 hours is simply the next day.
+    // Accepts FlightDuration, SignedFlightDuration or a whole-second chrono TimeDelta, and fails instead of
+    // panicking when the result leaves the supported range.
+    pub fn checked_add<D: TryInto<SignedFlightDuration>>(&self, duration: D) -> Result<TimePoint, TimePointError> {
+        let duration = duration.try_into().map_err(|_| TimePointError::InvalidDuration)?;
+        let seconds = self.to_seconds().checked_add(duration.as_seconds()).ok_or(TimePointError::OutOfRange)?;
+        return TimePoint::from_instant(seconds, self.utc_offset, self.zone, Precision::Second)?.with_precision(self.precision);
     }
 
-    pub fn calc_duration_with(&self, timepoint2: &TimePoint) -> i64 {
-        return TimePoint::calc_bias_with(self, timepoint2).abs();
+    pub fn checked_sub<D: TryInto<SignedFlightDuration>>(&self, duration: D) -> Result<TimePoint, TimePointError> {
+        let duration = duration.try_into().map_err(|_| TimePointError::InvalidDuration)?;
+        let seconds = self.to_seconds().checked_sub(duration.as_seconds()).ok_or(TimePointError::OutOfRange)?;
+        return TimePoint::from_instant(seconds, self.utc_offset, self.zone, Precision::Second)?.with_precision(self.precision);
     }
 
+    // certainly after: this period starts once the other one is over
     pub fn is_after(&self, other: &TimePoint) -> bool {
-        return self.calc_bias_with(other) > 0;
+        return self.to_seconds() >= other.period_end_seconds();
     }
 
+    // certainly before: this period is over before the other one starts
     pub fn is_before(&self, other: &TimePoint) -> bool {
-        return self.calc_bias_with(other) < 0;
+        return self.period_end_seconds() <= other.to_seconds();
     }
 
     pub fn is(&self, other: &TimePoint) -> bool {
-        return self.calc_bias_with(other) == 0;
+        return self == other;
     }
 
-    pub fn to_utc(&mut self, new_utc_zone: i32) {
-        // convert to standard time zone (STZ UTC+0)
-        let mut stz_hour: i32 = self.hour - self.utc_offset;
-        let mut stz_day: i32 = self.day;
-        let mut stz_month: i32 = self.month;
-        let mut stz_year: i32 = self.year;
+    // could be the same moment, e.g. "March 2009" and "2009-03-15 10:00"
+    pub fn overlaps(&self, other: &TimePoint) -> bool {
+        return !self.is_before(other) && !self.is_after(other);
+    }
 
-        let days_in_month = |m: i32, y: i32| -> i32 {
-            TimePoint::month_to_days(m, y)
-        };
+    // calendar arithmetic: the wall clock is kept and the day is clamped to the end of a shorter month,
+    // so Jan 31 + 1 month is the last day of February. In a zone the offset is resolved again, which
+    // fails if the new local time falls into a DST gap or overlap.
+    pub fn add_months(&self, months: i32) -> Result<TimePoint, TimePointError> {
+        let total_months = self.year as i64 * 12 + (self.month - 1) as i64 + months as i64;
+        let year = i32::try_from(total_months.div_euclid(12)).map_err(|_| TimePointError::OutOfRange)?;
+        let month = total_months.rem_euclid(12) as i32 + 1;
+        let day = self.day.min(TimePoint::month_to_days(month, year));
+        return self.with_date(year, month, day);
+    }
 
-        while stz_hour < 0 {
-            stz_hour += 24;
-            stz_day -= 1;
-            if stz_day < 1 {
-                stz_month -= 1;
-                if stz_month < 1 {
-                    stz_month += 12;
-                    stz_year -= 1;
-                }
-                stz_day += days_in_month(stz_month, stz_year);
-            }
-        }
-        while stz_hour >= 24 {
-            stz_hour -= 24;
-            stz_day += 1;
-            let dim = days_in_month(stz_month, stz_year);
-            if stz_day > dim {
-                stz_day -= dim;
-                stz_month += 1;
-                if stz_month > 12 {
-                    stz_month -= 12;
-                    stz_year += 1;
-                }
-            }
-        }
+    pub fn sub_months(&self, months: i32) -> Result<TimePoint, TimePointError> {
+        return self.add_months(months.checked_neg().ok_or(TimePointError::OutOfRange)?);
+    }
 
+    pub fn add_years(&self, years: i32) -> Result<TimePoint, TimePointError> {
+        return self.add_months(years.checked_mul(12).ok_or(TimePointError::OutOfRange)?);
+    }
 
-        // convert to new time zone (NTZ, UTC+n)
-        let mut ntz_hour: i32 = stz_hour + new_utc_zone;
-        let mut ntz_day: i32 = stz_day;
-        let mut ntz_month: i32 = stz_month;
-        let mut ntz_year: i32 = stz_year;
-
-        while ntz_hour < 0 {
-            ntz_hour += 24;
-            ntz_day -= 1;
-            if ntz_day < 1 {
-                ntz_month -= 1;
-                if ntz_month < 1 {
-                    ntz_month += 12;
-                    ntz_year -= 1;
-                }
-                ntz_day += days_in_month(ntz_month, ntz_year);
-            }
-        }
-        while ntz_hour >= 24 {
-            ntz_hour -= 24;
-            ntz_day += 1;
-            let dim = days_in_month(ntz_month, ntz_year);
-            if ntz_day > dim {
-                ntz_day -= dim;
-                ntz_month += 1;
-                if ntz_month > 12 {
-                    ntz_month -= 12;
-                    ntz_year += 1;
-                }
-            }
-        }
+    pub fn sub_years(&self, years: i32) -> Result<TimePoint, TimePointError> {
+        return self.add_months(years.checked_mul(-12).ok_or(TimePointError::OutOfRange)?);
+    }
 
-        self.year = ntz_year;
-        self.month = ntz_month;
-        self.day = ntz_day;
-        self.hour = ntz_hour;
-        self.utc_offset = new_utc_zone;
+    // 23:59:59 on the last day of the same month; a month- or year-precise value becomes a known date
+    pub fn end_of_month(&self) -> Result<TimePoint, TimePointError> {
+        return self.last_second_of(self.year, self.month, TimePoint::month_to_days(self.month, self.year));
     }
 
-    pub fn after(&mut self, year: i32, month: i32, day: i32, hour: i32, minute: i32, second: i32){
-        self.second += if second >= 0 {second} else {0};
-        self.minute += if minute >= 0 {minute} else {0};
-        self.hour += if hour >= 0 {hour} else {0};
-        self.day += if day >= 0 {day} else {0};
-        self.month += if month >= 0 {month} else {0};
-        self.year += if year >= 0 {year} else {0};
+    // e.g. "status expires end of next year": `earned.add_years(1)?.end_of_year()`
+    pub fn end_of_year(&self) -> Result<TimePoint, TimePointError> {
+        return self.last_second_of(self.year, 12, 31);
+    }
 
-        if self.second >= 60 {
-            self.minute += self.second / 60;
-            self.second %= 60;
-        }
+    // cut down to what was known, but at least the date
+    fn last_second_of(&self, year: i32, month: i32, day: i32) -> Result<TimePoint, TimePointError> {
+        let end = match self.zone {
+            Some(zone) => TimePoint::new_in_zone(year, month, day, 23, 59, 59, zone)?,
+            None => TimePoint::new(year, month, day, 23, 59, 59, self.utc_offset)?,
+        };
+        return end.with_precision(self.precision.max(Precision::Day));
+    }
 
-        if self.minute >= 60 {
-            self.hour += self.minute / 60;
-            self.minute %= 60;
-        }
+    fn with_date(&self, year: i32, month: i32, day: i32) -> Result<TimePoint, TimePointError> {
+        let moved = match self.zone {
+            Some(zone) => TimePoint::new_in_zone(year, month, day, self.hour, self.minute, self.second, zone)?,
+            None => TimePoint::new(year, month, day, self.hour, self.minute, self.second, self.utc_offset)?,
+        };
+        return Ok(TimePoint { precision: self.precision, ..moved });
+    }
 
-        if self.hour >= 24 {
-            self.day += self.hour / 24;
-            self.hour %= 24;
+    // a date, month or year is a calendar period where it was recorded, relabelling it would move the
+    // period (and with it equality and ordering), so it is left as it is
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_utc(&mut self, new_utc_offset: i32) -> Result<(), TimePointError> {
+        if !(MIN_UTC_OFFSET..=MAX_UTC_OFFSET).contains(&new_utc_offset) {
+            return Err(TimePointError::OffsetOutOfRange(new_utc_offset));
         }
-
-        if self.month > 12 {
-            self.year += (self.month - 1) / 12;
-            self.month = (self.month - 1) % 12 + 1;
+        if !self.is_exact() {
+            return Ok(());
         }
+        // shift the wall clock (in minutes of the day) from the current offset to the new one
+        let mut minutes: i32 = self.hour * 60 + self.minute - self.utc_offset + new_utc_offset;
+        let mut day: i32 = self.day;
+        let mut month: i32 = self.month;
+        let mut year: i32 = self.year;
 
-        while self.day > TimePoint::month_to_days(self.month, self.year) {
-            let days_in_month = TimePoint::month_to_days(self.month, self.year);
-            self.day -= days_in_month;
-            self.month += 1;
+        let days_in_month = |m: i32, y: i32| -> i32 {
+            TimePoint::month_to_days(m, y)
+        };
 
-            if self.month > 12 {
-                self.year += (self.month - 1) / 12;
-                self.month = (self.month - 1) % 12 + 1;
+        while minutes < 0 {
+            minutes += 24 * 60;
+            day -= 1;
+            if day < 1 {
+                month -= 1;
+                if month < 1 {
+                    month += 12;
+                    year = year.checked_sub(1).ok_or(TimePointError::OutOfRange)?;
+                }
+                day += days_in_month(month, year);
             }
         }
-    }
-
-    pub fn before(&mut self, year: i32, month: i32, day: i32, hour: i32, minute: i32, second: i32){
-        self.second -= if second >= 0 { second } else { 0 };
-        self.minute -= if minute >= 0 { minute } else { 0 };
-        self.hour -= if hour >= 0 { hour } else { 0 };
-        self.day -= if day >= 0 { day } else { 0 };
-        self.month -= if month >= 0 { month } else { 0 };
-        self.year -= if year >= 0 { year } else { 0 };
-
-        if self.second < 0 {
-            self.minute -= 1;
-            self.second += 60;
+        while minutes >= 24 * 60 {
+            minutes -= 24 * 60;
+            day += 1;
+            let dim = days_in_month(month, year);
+            if day > dim {
+                day -= dim;
+                month += 1;
+                if month > 12 {
+                    month -= 12;
+                    year = year.checked_add(1).ok_or(TimePointError::OutOfRange)?;
+                }
+            }
         }
 
-        if self.minute < 0 {
-            self.hour -= 1;
-            self.minute += 60;
-        }
+        self.year = year;
+        self.month = month;
+        self.day = day;
+        self.hour = minutes / 60;
+        self.minute = minutes % 60;
+        self.utc_offset = new_utc_offset;
+        self.zone = None;
+        return Ok(());
+    }
 
-        if self.hour < 0 {
-            self.day -= 1;
-            self.hour += 24;
+    // same instant on the zone's wall clock; a date, month or year is left as it is, see `to_utc`.
+    // Left unchanged when the instant is beyond what the zone data covers.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_zone(&mut self, zone: Tz) -> Result<(), TimePointError> {
+        if !self.is_exact() {
+            return Ok(());
         }
+        *self = TimePoint::from_instant(self.to_seconds(), self.utc_offset, Some(zone), self.precision)?;
+        return Ok(());
+    }
 
-        while self.month < 1 {
-            self.year -= 1;
-            self.month += 12;
-        }
+    // wall-clock fields for an instant; a zone overrides the given offset with its own at that instant
+    fn from_instant(seconds: i64, utc_offset: i32, zone: Option<Tz>, precision: Precision) -> Result<Self, TimePointError> {
+        let instant = DateTime::from_timestamp(seconds, 0).ok_or(TimePointError::OutOfRange)?;
+        let utc_offset = match zone {
+            Some(zone) => zone.offset_from_utc_datetime(&instant.naive_utc()).fix().local_minus_utc() / 60,
+            None => utc_offset,
+        };
+        let local = instant.naive_utc().checked_add_signed(TimeDelta::minutes(utc_offset as i64)).ok_or(TimePointError::OutOfRange)?;
+        return Ok(TimePoint {
+            year: local.year(),
+            month: local.month() as i32,
+            day: local.day() as i32,
+            hour: local.hour() as i32,
+            minute: local.minute() as i32,
+            second: local.second() as i32,
+            utc_offset,
+            zone,
+            precision
+        });
+    }
 
-        while self.day < 1 {
-            self.month -= 1;
-            if self.month < 1 {
-                self.year -= 1;
-                self.month = 12;
-            }
-            self.day += TimePoint::month_to_days(self.month, self.year);
+    // callers validate the fields first, so None means the year is beyond what chrono can represent
+    fn naive_local(year: i32, month: i32, day: i32, hour: i32, minute: i32, second: i32) -> Option<NaiveDateTime> {
+        return NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+            .and_then(|date| date.and_hms_opt(hour as u32, minute as u32, second as u32));
+    }
+
+    // settles the current wall clock in the zone again, e.g. after the fields were truncated
+    fn resolve_wall_clock(&mut self, policy: DstPolicy) -> Result<(), TimePointError> {
+        if let Some(zone) = self.zone {
+            let resolved = TimePoint::new_in_zone_with(self.year, self.month, self.day, self.hour, self.minute, self.second, zone, policy)?;
+            *self = TimePoint { precision: self.precision, ..resolved };
         }
+        return Ok(());
     }
 
-    pub fn get_now(&mut self) {
-        let now = Local::now();
-        let utc = now.offset().fix().local_minus_utc() / 3600;
-        self.year = now.year();
-        self.month = now.month() as i32;
-        self.day = now.day() as i32;
-        self.hour = now.hour() as i32;
-        self.minute = now.minute() as i32;
-        self.second = now.second() as i32;
-        self.utc_offset = utc;
+    // offset (in seconds) of the last local time that exists before the gap `naive` falls into;
+    // gaps are at most a day long, e.g. Samoa skipping 2011-12-30
+    fn offset_before_gap(zone: Tz, naive: NaiveDateTime) -> Option<i32> {
+        return (1..=2 * 24 * 60)
+            .filter_map(|minutes| naive.checked_sub_signed(TimeDelta::minutes(minutes)))
+            .find_map(|earlier| zone.from_local_datetime(&earlier).latest())
+            .map(|dt| dt.offset().fix().local_minus_utc());
     }
 
     fn is_leap_year(year: i32) -> bool {
         return (year % 4 == 0 && year % 100 != 0) || (year % 400 == 0);
     }
 
-    fn year_to_days(year: i32) -> i32 {
-        return if TimePoint::is_leap_year(year) { 366 } else { 365 };
-    }
-
     fn month_to_days(month: i32, year: i32) -> i32 {
         match month {
             1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
@@ -231,24 +379,37 @@ impl TimePoint {
         }
     }
 
-    fn to_seconds(&self) -> i64 {
-        let mut total_days: i64 = 0;
-
-        for year in 1970..self.year {
-            total_days += TimePoint::year_to_days(year) as i64;
-        }
-
-        for month in 1..self.month {
-            total_days += TimePoint::month_to_days(month, self.year) as i64;
-        }
+    // days since 1970-01-01 in the proleptic Gregorian calendar with astronomical years (1 BC is year 0),
+    // counted in closed form so dates before the epoch and negative years work as well
+    pub(crate) fn days_from_epoch(year: i32, month: i32, day: i32) -> i64 {
+        let month = month as i64;
+        let shifted_year = year as i64 - if month <= 2 { 1 } else { 0 }; // years start in March
+        let era = shifted_year.div_euclid(400);
+        let year_of_era = shifted_year.rem_euclid(400);
+        let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        return era * 146097 + day_of_era - 719468;
+    }
 
-        total_days += (self.day - 1) as i64;
+    fn to_seconds(&self) -> i64 {
+        let total_days = TimePoint::days_from_epoch(self.year, self.month, self.day);
+        return total_days * 86400 + (self.hour as i64) * 3600 + (self.minute as i64) * 60 + (self.second as i64) - (self.utc_offset as i64) * 60;
+    }
 
-        return total_days * 86400 + (self.hour as i64) * 3600 + (self.minute as i64) * 60 + (self.second as i64) - (self.utc_offset as i64) * 3600;
+    // first second after the period this TimePoint stands for
+    fn period_end_seconds(&self) -> i64 {
+        let days = match self.precision {
+            Precision::Year => if TimePoint::is_leap_year(self.year) { 366 } else { 365 },
+            Precision::Month => TimePoint::month_to_days(self.month, self.year) as i64,
+            Precision::Day => 1,
+            Precision::Minute => return self.to_seconds() + 60,
+            Precision::Second => return self.to_seconds() + 1,
+        };
+        return self.to_seconds() + days * 86400;
     }
 
     fn valid_date(year: i32, month: i32, day: i32) -> bool {
-        if month <= 12 && month >= 1 && day <= TimePoint::month_to_days(month, year) && day >= 1 {
+        if (1..=12).contains(&month) && (1..=TimePoint::month_to_days(month, year)).contains(&day) {
             return true;
         }
 
@@ -256,19 +417,273 @@ impl TimePoint {
     }
 
     fn valid_time(hour: i32, minute:i32, second: i32) -> bool {
-        if hour <= 23 && hour >= 0 && minute <= 59 && minute >= 0 && second <= 59 && second >= 0 {
+        if (0..=23).contains(&hour) && (0..=59).contains(&minute) && (0..=59).contains(&second) {
             return true;
         }
 
         return false;
     }
 
-    fn valid_timepoint(year: i32, month: i32, day: i32, hour: i32, minute: i32, second: i32, utc_offset: i32) -> bool {
-        if utc_offset > 13 || utc_offset < -13 {
-            return false;
+    fn validate_date_time(year: i32, month: i32, day: i32, hour: i32, minute: i32, second: i32) -> Result<(), TimePointError> {
+        if !(1..=12).contains(&month) {
+            return Err(TimePointError::InvalidMonth(month));
+        }
+        if !TimePoint::valid_date(year, month, day) {
+            return Err(TimePointError::InvalidDay { year, month, day });
+        }
+        if !TimePoint::valid_time(hour, minute, second) {
+            return Err(TimePointError::InvalidTime { hour, minute, second });
+        }
+        return Ok(());
+    }
+
+    fn validate_fields(year: i32, month: i32, day: i32, hour: i32, minute: i32, second: i32, utc_offset: i32) -> Result<(), TimePointError> {
+        TimePoint::validate_date_time(year, month, day, hour, minute, second)?;
+        if !(MIN_UTC_OFFSET..=MAX_UTC_OFFSET).contains(&utc_offset) {
+            return Err(TimePointError::OffsetOutOfRange(utc_offset));
+        }
+        return Ok(());
+    }
+}
+
+// what a local wall-clock time turns into in an IANA zone
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum LocalTimeResolution {
+    Unique(TimePoint),
+    Ambiguous(TimePoint, TimePoint), // earlier and later instant around a fall-back
+    NonExistent // skipped by a spring-forward
+}
+
+// how to settle a local time that does not resolve to exactly one instant
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum DstPolicy {
+    Reject,
+    Earlier,
+    Later,
+    ShiftForward // like Later, and a time in a gap moves forward by the length of the gap
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum TimePointError {
+    InvalidMonth(i32),
+    InvalidDay { year: i32, month: i32, day: i32 },
+    InvalidTime { hour: i32, minute: i32, second: i32 },
+    OffsetOutOfRange(i32), // unit: minutes
+    NonExistentLocalTime { zone: Tz }, // the wall-clock time falls into a DST gap
+    AmbiguousLocalTime { zone: Tz, earlier_offset: i32, later_offset: i32 }, // the wall-clock time occurs twice
+    InvalidFormat(String), // the text that could not be parsed
+    MissingZone, // an IANA zone is required but the TimePoint only has a fixed offset
+    OutOfRange, // the instant lies beyond the calendar range chrono can convert
+    InvalidDuration // a duration with a fractional second or beyond i64 seconds
+}
+
+impl fmt::Display for TimePointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            TimePointError::InvalidMonth(month) => write!(f, "invalid month {}", month),
+            TimePointError::InvalidDay { year, month, day } => write!(f, "invalid day {} for {:04}-{:02}", day, year, month),
+            TimePointError::InvalidTime { hour, minute, second } => write!(f, "invalid time of day {:02}:{:02}:{:02}", hour, minute, second),
+            TimePointError::OffsetOutOfRange(offset) => write!(f, "UTC offset of {} minutes is out of range", offset),
+            TimePointError::NonExistentLocalTime { zone } => write!(f, "local time does not exist in {}", zone.name()),
+            TimePointError::AmbiguousLocalTime { zone, earlier_offset, later_offset } =>
+                write!(f, "local time occurs twice in {} (offsets {} and {} minutes)", zone.name(), earlier_offset, later_offset),
+            TimePointError::InvalidFormat(text) => write!(f, "\"{}\" is not a date and time such as 2025-10-01T10:32:17+08:00 or 2025-10-01 10:32", text),
+            TimePointError::MissingZone => write!(f, "time point has no IANA zone"),
+            TimePointError::OutOfRange => write!(f, "time point is out of the supported range"),
+            TimePointError::InvalidDuration => write!(f, "duration is not a whole number of seconds or is too long"),
+        };
+    }
+}
+
+impl std::error::Error for TimePointError {}
+
+impl FromStr for TimePoint {
+    type Err = TimePointError;
+
+    // TimePoint counts whole seconds, so a fractional second is rejected rather than dropped
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim();
+        let parsed = DateTime::parse_from_rfc3339(text).map_err(|_| TimePointError::InvalidFormat(text.to_string()))?;
+        if parsed.nanosecond() != 0 {
+            return Err(TimePointError::InvalidFormat(text.to_string()));
         }
+        return TimePoint::try_from(parsed);
+    }
+}
+
+// chrono values are cut down to the whole second, e.g. the machine clock
+impl TryFrom<DateTime<FixedOffset>> for TimePoint {
+    type Error = TimePointError;
+
+    fn try_from(value: DateTime<FixedOffset>) -> Result<Self, Self::Error> {
+        return TimePoint::new(value.year(), value.month() as i32, value.day() as i32,
+            value.hour() as i32, value.minute() as i32, value.second() as i32,
+            value.offset().local_minus_utc() / 60);
+    }
+}
+
+impl TryFrom<DateTime<Tz>> for TimePoint {
+    type Error = TimePointError;
+
+    fn try_from(value: DateTime<Tz>) -> Result<Self, Self::Error> {
+        let mut tp = TimePoint::try_from(value.fixed_offset())?;
+        tp.zone = Some(value.timezone());
+        return Ok(tp);
+    }
+}
+
+impl From<DateTime<Utc>> for TimePoint {
+    fn from(value: DateTime<Utc>) -> Self {
+        return TimePoint {
+            year: value.year(),
+            month: value.month() as i32,
+            day: value.day() as i32,
+            hour: value.hour() as i32,
+            minute: value.minute() as i32,
+            second: value.second() as i32,
+            utc_offset: 0,
+            zone: None,
+            precision: Precision::Second
+        };
+    }
+}
+
+impl TryFrom<&TimePoint> for DateTime<FixedOffset> {
+    type Error = TimePointError;
+
+    fn try_from(value: &TimePoint) -> Result<Self, Self::Error> {
+        value.validate()?;
+        let offset = FixedOffset::east_opt(value.utc_offset * 60).ok_or(TimePointError::OffsetOutOfRange(value.utc_offset))?;
+        return offset
+            .with_ymd_and_hms(value.year, value.month as u32, value.day as u32, value.hour as u32, value.minute as u32, value.second as u32)
+            .single()
+            .ok_or(TimePointError::OutOfRange); // the fields are valid, so only the year can be beyond chrono
+    }
+}
+
+impl TryFrom<&TimePoint> for DateTime<Utc> {
+    type Error = TimePointError;
+
+    fn try_from(value: &TimePoint) -> Result<Self, Self::Error> {
+        return DateTime::<FixedOffset>::try_from(value).map(|dt| dt.with_timezone(&Utc));
+    }
+}
+
+// the instant is kept, so a stale `utc_offset` is corrected to what the zone says
+impl TryFrom<&TimePoint> for DateTime<Tz> {
+    type Error = TimePointError;
+
+    fn try_from(value: &TimePoint) -> Result<Self, Self::Error> {
+        let zone = value.zone.ok_or(TimePointError::MissingZone)?;
+        return DateTime::<FixedOffset>::try_from(value).map(|dt| dt.with_timezone(&zone));
+    }
+}
+
+impl PartialEq for TimePoint {
+    fn eq(&self, other: &Self) -> bool {
+        return self.to_seconds() == other.to_seconds() && self.precision == other.precision;
+    }
+}
+
+impl Eq for TimePoint {}
+
+impl PartialOrd for TimePoint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl Ord for TimePoint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // a coarse period sorts before finer points that start at the same instant
+        return self.to_seconds().cmp(&other.to_seconds()).then(self.precision.cmp(&other.precision));
+    }
+}
+
+impl Hash for TimePoint {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_seconds().hash(state);
+        self.precision.hash(state);
+    }
+}
+
+// `+` and `-` with a duration panic where `checked_add`/`checked_sub` would return an error,
+// like std::time::Instant + Duration; use those when the duration comes from user input
+impl<D: TryInto<SignedFlightDuration>> Add<D> for &TimePoint {
+    type Output = TimePoint;
 
-        return TimePoint::valid_date(year, month, day) && TimePoint::valid_time(hour, minute, second);
+    fn add(self, rhs: D) -> TimePoint {
+        return self.checked_add(rhs).unwrap_or_else(|error| panic!("cannot add duration to {}: {}", self, error));
+    }
+}
+
+impl<D: TryInto<SignedFlightDuration>> Add<D> for TimePoint {
+    type Output = TimePoint;
+
+    fn add(self, rhs: D) -> TimePoint {
+        return &self + rhs;
+    }
+}
+
+impl<D: TryInto<SignedFlightDuration>> AddAssign<D> for TimePoint {
+    fn add_assign(&mut self, rhs: D) {
+        *self = &*self + rhs;
+    }
+}
+
+impl<D: TryInto<SignedFlightDuration>> Sub<D> for &TimePoint {
+    type Output = TimePoint;
+
+    fn sub(self, rhs: D) -> TimePoint {
+        return self.checked_sub(rhs).unwrap_or_else(|error| panic!("cannot subtract duration from {}: {}", self, error));
+    }
+}
+
+impl<D: TryInto<SignedFlightDuration>> Sub<D> for TimePoint {
+    type Output = TimePoint;
+
+    fn sub(self, rhs: D) -> TimePoint {
+        return &self - rhs;
+    }
+}
+
+impl<D: TryInto<SignedFlightDuration>> SubAssign<D> for TimePoint {
+    fn sub_assign(&mut self, rhs: D) {
+        *self = &*self - rhs;
+    }
+}
+
+impl Sub<&TimePoint> for &TimePoint {
+    type Output = SignedFlightDuration;
+
+    fn sub(self, rhs: &TimePoint) -> SignedFlightDuration {
+        return SignedFlightDuration::from_seconds(self.to_seconds() - rhs.to_seconds());
+    }
+}
+
+impl Sub for TimePoint {
+    type Output = SignedFlightDuration;
+
+    fn sub(self, rhs: TimePoint) -> SignedFlightDuration {
+        return &self - &rhs;
+    }
+}
+
+impl fmt::Display for TimePoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // only what is known is printed, a calendar date goes without its offset
+        match self.precision {
+            Precision::Year => return write!(f, "{:04}", self.year),
+            Precision::Month => return write!(f, "{:04}-{:02}", self.year, self.month),
+            Precision::Day => return write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day),
+            Precision::Minute => write!(f, "{:04}-{:02}-{:02} {:02}:{:02}", self.year, self.month, self.day, self.hour, self.minute)?,
+            Precision::Second => write!(f, "{:04}-{:02}-{:02} {:02}:{:02}:{:02}", self.year, self.month, self.day, self.hour, self.minute, self.second)?,
+        }
+        write!(f, " UTC{}", self.format_offset())?;
+        if let Some(zone) = self.zone {
+            write!(f, " {}", zone.name())?;
+        }
+        return Ok(());
     }
 }
 
@@ -278,161 +693,627 @@ mod test_time_point {
 
     #[test]
     fn test_in_same_day() {
-        let tp1 = TimePoint::new(2023, 3, 15, 12, 0, 0, 1);
-        let tp2 = TimePoint::new(2023, 3, 15, 13, 0, 0, 1);
-        assert_eq!(TimePoint::calc_duration_with(&tp1, &tp2), 3600);
+        let tp1 = TimePoint::new(2023, 3, 15, 12, 0, 0, 60).unwrap();
+        let tp2 = TimePoint::new(2023, 3, 15, 13, 0, 0, 60).unwrap();
+        assert_eq!(TimePoint::calc_duration_with(&tp1, &tp2).as_seconds(), 3600);
     }
 
     #[test]
     fn test_in_different_day() {
-        let tp1 = TimePoint::new(2023, 3, 15, 12, 0, 0, 1);
-        let tp2 = TimePoint::new(2023, 3, 16, 12, 0, 0, 1);
-        assert_eq!(TimePoint::calc_duration_with(&tp1, &tp2), 86400);
+        let tp1 = TimePoint::new(2023, 3, 15, 12, 0, 0, 60).unwrap();
+        let tp2 = TimePoint::new(2023, 3, 16, 12, 0, 0, 60).unwrap();
+        assert_eq!(TimePoint::calc_duration_with(&tp1, &tp2).as_seconds(), 86400);
     }
 
     #[test]
     fn test_leap_year() {
-        let tp1 = TimePoint::new(2020, 2, 29, 12, 0, 0, 1);
-        let tp2 = TimePoint::new(2020, 3, 1, 12, 0, 0, 1);
-        assert_eq!(TimePoint::calc_duration_with(&tp1, &tp2), 86400);
+        let tp1 = TimePoint::new(2020, 2, 29, 12, 0, 0, 60).unwrap();
+        let tp2 = TimePoint::new(2020, 3, 1, 12, 0, 0, 60).unwrap();
+        assert_eq!(TimePoint::calc_duration_with(&tp1, &tp2).as_seconds(), 86400);
     }
 
     #[test]
     fn test_with_different_timezone() {
-        let tp1 = TimePoint::new(2023, 3, 15, 12, 0, 0, 1);
-        let tp2 = TimePoint::new(2023, 3, 15, 12, 0, 0, 2);
-        assert_eq!(TimePoint::calc_duration_with(&tp1, &tp2), 3600);
+        let tp1 = TimePoint::new(2023, 3, 15, 12, 0, 0, 60).unwrap();
+        let tp2 = TimePoint::new(2023, 3, 15, 12, 0, 0, 2 * 60).unwrap();
+        assert_eq!(TimePoint::calc_duration_with(&tp1, &tp2).as_seconds(), 3600);
     }
 
     #[test]
     fn test_signed_timezone() {
-        let tp1 = TimePoint::new(2023, 3, 15, 12, 0, 0, 1);
-        let tp2 = TimePoint::new(2023, 3, 15, 12, 0, 0, -1);
-        assert_eq!(TimePoint::calc_duration_with(&tp1, &tp2), 7200);
+        let tp1 = TimePoint::new(2023, 3, 15, 12, 0, 0, 60).unwrap();
+        let tp2 = TimePoint::new(2023, 3, 15, 12, 0, 0, -60).unwrap();
+        assert_eq!(TimePoint::calc_duration_with(&tp1, &tp2).as_seconds(), 7200);
     }
 
     #[test]
     fn test_calc_tp_bias_with_same_utc() {
-        let tp1 = TimePoint::new(2023, 3, 15, 12, 0, 0, 1);
-        let tp2 = TimePoint::new(2023, 3, 15, 13, 0, 0, 1);
-        assert_eq!(TimePoint::calc_bias_with(&tp1, &tp2), -3600);
+        let tp1 = TimePoint::new(2023, 3, 15, 12, 0, 0, 60).unwrap();
+        let tp2 = TimePoint::new(2023, 3, 15, 13, 0, 0, 60).unwrap();
+        assert_eq!(&tp1 - &tp2, SignedFlightDuration::from_hours(-1));
     }
 
     #[test]
     fn test_calc_tp_bias_with_different_utc() {
-        let tp1 = TimePoint::new(2023, 3, 15, 12, 0, 0, 1);
-        let tp2 = TimePoint::new(2023, 3, 15, 12, 0, 0, 2);
-        assert_eq!(TimePoint::calc_bias_with(&tp1, &tp2), 3600);
+        let tp1 = TimePoint::new(2023, 3, 15, 12, 0, 0, 60).unwrap();
+        let tp2 = TimePoint::new(2023, 3, 15, 12, 0, 0, 2 * 60).unwrap();
+        assert_eq!(&tp1 - &tp2, SignedFlightDuration::from_hours(1));
     }
 
     #[test]
     fn test_to_string() {
-        let tp = TimePoint::new(2023, 3, 15, 12, 0, 0, 1);
-        assert_eq!(tp.to_string(), "2023-03-15 12:00:00 UTC+1");
-        let tp2 = TimePoint::new(2023, 3, 15, 12, 0, 0, -1);
-        assert_eq!(tp2.to_string(), "2023-03-15 12:00:00 UTC-1");
+        let tp = TimePoint::new(2023, 3, 15, 12, 0, 0, 60).unwrap();
+        assert_eq!(tp.to_string(), "2023-03-15 12:00:00 UTC+01:00");
+        let tp2 = TimePoint::new(2023, 3, 15, 12, 0, 0, -60).unwrap();
+        assert_eq!(tp2.to_string(), "2023-03-15 12:00:00 UTC-01:00");
     }
 
     #[test]
     fn test_is_after() {
-        let tp1 = TimePoint::new(-2023, 3, 15, 12, 0, 0, 1);
-        let tp2 = TimePoint::new(2023, 3, 15, 13, 0, 0, 1);
-        assert_eq!(tp1.is_after(&tp2), false);
-        assert_eq!(tp2.is_after(&tp1), true);
+        let tp1 = TimePoint::new(-2023, 3, 15, 12, 0, 0, 60).unwrap();
+        let tp2 = TimePoint::new(2023, 3, 15, 13, 0, 0, 60).unwrap();
+        assert!(!tp1.is_after(&tp2));
+        assert!(tp2.is_after(&tp1));
+    }
+
+    #[test]
+    fn test_to_seconds_before_epoch() {
+        assert_eq!(TimePoint::new(1970, 1, 1, 0, 0, 0, 0).unwrap().to_seconds(), 0);
+        assert_eq!(TimePoint::new(1969, 12, 31, 23, 59, 59, 0).unwrap().to_seconds(), -1);
+        assert_eq!(TimePoint::new(1970, 1, 1, 8, 0, 0, 8 * 60).unwrap().to_seconds(), 0);
+        assert_eq!(TimePoint::new(1964, 10, 1, 0, 0, 0, 9 * 60).unwrap().to_seconds(), -165_747_600);
+    }
+
+    #[test]
+    fn test_to_seconds_matches_chrono() {
+        for (year, month, day) in [(-2023, 3, 15), (-1, 12, 31), (0, 2, 29), (1, 1, 1), (1600, 2, 29), (1900, 3, 1), (1969, 7, 20), (2000, 2, 29), (2100, 12, 31)] {
+            let tp = TimePoint::new(year, month, day, 12, 30, 0, 0).unwrap();
+            let expected = Utc.with_ymd_and_hms(year, month as u32, day as u32, 12, 30, 0).unwrap().timestamp();
+            assert_eq!(tp.to_seconds(), expected);
+        }
+    }
+
+    #[test]
+    fn test_compare_across_1970() {
+        let in_service = TimePoint::new(1969, 2, 9, 10, 0, 0, -8 * 60).unwrap();
+        let retired = TimePoint::new(1971, 1, 1, 0, 0, 0, 0).unwrap();
+        assert!(in_service.is_before(&retired));
+        assert!(retired.is_after(&in_service));
+        let earlier = TimePoint::new(1950, 6, 1, 0, 0, 0, 0).unwrap();
+        assert!(earlier.is_before(&in_service));
+        assert_eq!(in_service - earlier, SignedFlightDuration::from_hours(6828 * 24 + 18));
     }
 
     #[test]
     fn test_is_before() {
-        let tp1 = TimePoint::new(2023, 3, 15, 12, 0, 0, 1);
-        let tp2 = TimePoint::new(2023, 3, 15, 13, 0, 0, 1);
-        assert_eq!(tp1.is_before(&tp2), true);
-        assert_eq!(tp2.is_before(&tp1), false);
+        let tp1 = TimePoint::new(2023, 3, 15, 12, 0, 0, 60).unwrap();
+        let tp2 = TimePoint::new(2023, 3, 15, 13, 0, 0, 60).unwrap();
+        assert!(tp1.is_before(&tp2));
+        assert!(!tp2.is_before(&tp1));
     }
 
     #[test]
     fn test_is() {
-        let tp1 = TimePoint::new(2023, 3, 15, 12, 0, 0, 1);
-        let tp2 = TimePoint::new(2023, 3, 15, 12, 0, 0, 1);
-        assert_eq!(tp1.is(&tp2), true);
-        assert_eq!(tp2.is(&tp1), true);
+        let tp1 = TimePoint::new(2023, 3, 15, 12, 0, 0, 60).unwrap();
+        let tp2 = TimePoint::new(2023, 3, 15, 12, 0, 0, 60).unwrap();
+        assert!(tp1.is(&tp2));
+        assert!(tp2.is(&tp1));
     }
 
     #[test]
     fn test_is_valid_date() {
-        assert_eq!(TimePoint::valid_date(2020, 1, 1), true);
-        assert_eq!(TimePoint::valid_date(2020, 1, 55), false);
+        assert!(TimePoint::valid_date(2020, 1, 1));
+        assert!(!TimePoint::valid_date(2020, 1, 55));
     }
 
     #[test]
     fn test_is_valid_time() {
-        assert_eq!(TimePoint::valid_time(0, 5, 60), false);
-        assert_eq!(TimePoint::valid_time(0, 0, 0), true);
+        assert!(!TimePoint::valid_time(0, 5, 60));
+        assert!(TimePoint::valid_time(0, 0, 0));
+    }
+
+    #[test]
+    fn test_new_rejects_invalid_fields() {
+        assert_eq!(TimePoint::new(2023, 13, 1, 0, 0, 0, 0), Err(TimePointError::InvalidMonth(13)));
+        assert_eq!(TimePoint::new(2023, 2, 29, 0, 0, 0, 0), Err(TimePointError::InvalidDay { year: 2023, month: 2, day: 29 }));
+        assert_eq!(TimePoint::new(2023, 2, 28, 24, 0, 0, 0), Err(TimePointError::InvalidTime { hour: 24, minute: 0, second: 0 }));
+        assert_eq!(TimePoint::new(2023, 2, 28, 0, 0, 0, 15 * 60), Err(TimePointError::OffsetOutOfRange(15 * 60)));
+        assert_eq!(TimePoint::new(2023, 2, 28, 0, 0, 0, -13 * 60), Err(TimePointError::OffsetOutOfRange(-13 * 60)));
+    }
+
+    #[test]
+    fn test_validate_after_edit() {
+        let mut tp = TimePoint::new(2023, 3, 15, 12, 0, 0, 60).unwrap();
+        assert_eq!(tp.validate(), Ok(()));
+        tp.day = 32;
+        assert_eq!(tp.validate(), Err(TimePointError::InvalidDay { year: 2023, month: 3, day: 32 }));
     }
 
     #[test]
     fn test_to_utc() {
-        let mut origin: TimePoint = TimePoint::new(2020, 1, 1, 23, 0, 0, 8);
-        origin.to_utc(9);
-        assert_eq!(origin.to_string(), "2020-01-02 00:00:00 UTC+9");
-        origin.to_utc(0);
-        assert_eq!(origin.to_string(), "2020-01-01 15:00:00 UTC+0");
-        origin.to_utc(1);
-        assert_eq!(origin.to_string(), "2020-01-01 16:00:00 UTC+1");
-        origin.to_utc(-5);
-        assert_eq!(origin.to_string(), "2020-01-01 10:00:00 UTC-5");
+        let mut origin: TimePoint = TimePoint::new(2020, 1, 1, 23, 0, 0, 8 * 60).unwrap();
+        origin.to_utc(9 * 60).unwrap();
+        assert_eq!(origin.to_string(), "2020-01-02 00:00:00 UTC+09:00");
+        origin.to_utc(0).unwrap();
+        assert_eq!(origin.to_string(), "2020-01-01 15:00:00 UTC+00:00");
+        origin.to_utc(60).unwrap();
+        assert_eq!(origin.to_string(), "2020-01-01 16:00:00 UTC+01:00");
+        origin.to_utc(-5 * 60).unwrap();
+        assert_eq!(origin.to_string(), "2020-01-01 10:00:00 UTC-05:00");
+
+        assert_eq!(origin.to_utc(99 * 60), Err(TimePointError::OffsetOutOfRange(99 * 60)));
+        assert_eq!(origin.to_utc(-13 * 60), Err(TimePointError::OffsetOutOfRange(-13 * 60)));
+        assert_eq!(origin.to_string(), "2020-01-01 10:00:00 UTC-05:00");
+        let mut last = TimePoint::new(i32::MAX, 12, 31, 23, 0, 0, 0).unwrap();
+        assert_eq!(last.to_utc(14 * 60), Err(TimePointError::OutOfRange));
+        assert_eq!(last.year, i32::MAX);
     }
 
     #[test]
     fn test_to_utc_a() {
-        let mut origin: TimePoint = TimePoint::new(2020, 1, 1, 1, 0, 0, 8);
-        origin.to_utc(9);
-        assert_eq!(origin.to_string(), "2020-01-01 02:00:00 UTC+9");
-        origin.to_utc(0);
-        assert_eq!(origin.to_string(), "2019-12-31 17:00:00 UTC+0");
-        origin.to_utc(1);
-        assert_eq!(origin.to_string(), "2019-12-31 18:00:00 UTC+1");
-        origin.to_utc(-5);
-        assert_eq!(origin.to_string(), "2019-12-31 12:00:00 UTC-5");
+        let mut origin: TimePoint = TimePoint::new(2020, 1, 1, 1, 0, 0, 8 * 60).unwrap();
+        origin.to_utc(9 * 60).unwrap();
+        assert_eq!(origin.to_string(), "2020-01-01 02:00:00 UTC+09:00");
+        origin.to_utc(0).unwrap();
+        assert_eq!(origin.to_string(), "2019-12-31 17:00:00 UTC+00:00");
+        origin.to_utc(60).unwrap();
+        assert_eq!(origin.to_string(), "2019-12-31 18:00:00 UTC+01:00");
+        origin.to_utc(-5 * 60).unwrap();
+        assert_eq!(origin.to_string(), "2019-12-31 12:00:00 UTC-05:00");
+    }
+
+    #[test]
+    fn test_fractional_offset_to_string() {
+        let ktm = TimePoint::new(2024, 5, 1, 9, 15, 0, 5 * 60 + 45).unwrap();
+        assert_eq!(ktm.to_string(), "2024-05-01 09:15:00 UTC+05:45");
+        let yyt = TimePoint::new(2024, 5, 1, 9, 15, 0, -(3 * 60 + 30)).unwrap();
+        assert_eq!(yyt.to_string(), "2024-05-01 09:15:00 UTC-03:30");
+    }
+
+    #[test]
+    fn test_fractional_offset_duration() {
+        let del = TimePoint::new(2024, 5, 1, 12, 0, 0, 5 * 60 + 30).unwrap();
+        let ktm = TimePoint::new(2024, 5, 1, 12, 15, 0, 5 * 60 + 45).unwrap();
+        assert_eq!(del, ktm);
+        let cht = TimePoint::new(2024, 5, 2, 0, 45, 0, 12 * 60 + 45).unwrap();
+        assert_eq!(cht.calc_duration_with(&del).as_seconds(), 5 * 3600 + 30 * 60);
+    }
+
+    #[test]
+    fn test_fractional_offset_to_utc() {
+        let mut origin: TimePoint = TimePoint::new(2024, 1, 1, 0, 10, 0, 5 * 60 + 30).unwrap();
+        origin.to_utc(0).unwrap();
+        assert_eq!(origin.to_string(), "2023-12-31 18:40:00 UTC+00:00");
+        origin.to_utc(12 * 60 + 45).unwrap();
+        assert_eq!(origin.to_string(), "2024-01-01 07:25:00 UTC+12:45");
+        origin.to_utc(9 * 60 + 30).unwrap();
+        assert_eq!(origin.to_string(), "2024-01-01 04:10:00 UTC+09:30");
+    }
+
+    #[test]
+    fn test_new_in_zone_follows_dst() {
+        let summer = TimePoint::new_in_zone(2024, 7, 1, 9, 30, 0, chrono_tz::Europe::London).unwrap();
+        assert_eq!(summer.utc_offset, 60);
+        assert_eq!(summer.to_string(), "2024-07-01 09:30:00 UTC+01:00 Europe/London");
+        let winter = TimePoint::new_in_zone(2024, 12, 1, 9, 30, 0, chrono_tz::Europe::London).unwrap();
+        assert_eq!(winter.utc_offset, 0);
+        let pek = TimePoint::new_in_zone(2024, 7, 1, 9, 30, 0, chrono_tz::Asia::Shanghai).unwrap();
+        assert_eq!(pek.utc_offset, 8 * 60);
+        assert_eq!(summer - pek, SignedFlightDuration::from_hours(7));
+    }
+
+    #[test]
+    fn test_new_in_zone_rejects_dst_gap() {
+        let gap = TimePoint::new_in_zone(2024, 3, 31, 1, 30, 0, chrono_tz::Europe::London);
+        assert_eq!(gap, Err(TimePointError::NonExistentLocalTime { zone: chrono_tz::Europe::London }));
+    }
+
+    #[test]
+    fn test_resolve_in_zone() {
+        let berlin = chrono_tz::Europe::Berlin;
+        assert_eq!(TimePoint::resolve_in_zone(2024, 3, 31, 2, 30, 0, berlin), Ok(LocalTimeResolution::NonExistent));
+        match TimePoint::resolve_in_zone(2024, 10, 27, 2, 30, 0, berlin).unwrap() {
+            LocalTimeResolution::Ambiguous(earlier, later) => {
+                assert_eq!(earlier.to_string(), "2024-10-27 02:30:00 UTC+02:00 Europe/Berlin");
+                assert_eq!(later.to_string(), "2024-10-27 02:30:00 UTC+01:00 Europe/Berlin");
+                assert_eq!(later - earlier, SignedFlightDuration::from_hours(1));
+            },
+            other => panic!("expected an ambiguous time, got {:?}", other),
+        }
+        match TimePoint::resolve_in_zone(2024, 10, 27, 4, 0, 0, berlin).unwrap() {
+            LocalTimeResolution::Unique(tp) => assert_eq!(tp.utc_offset, 60),
+            other => panic!("expected a unique time, got {:?}", other),
+        }
+        assert_eq!(TimePoint::resolve_in_zone(2024, 2, 30, 2, 30, 0, berlin), Err(TimePointError::InvalidDay { year: 2024, month: 2, day: 30 }));
+    }
+
+    #[test]
+    fn test_dst_policies() {
+        let berlin = chrono_tz::Europe::Berlin;
+        assert_eq!(TimePoint::new_in_zone(2024, 10, 27, 2, 30, 0, berlin),
+            Err(TimePointError::AmbiguousLocalTime { zone: berlin, earlier_offset: 2 * 60, later_offset: 60 }));
+        assert_eq!(TimePoint::new_in_zone_with(2024, 10, 27, 2, 30, 0, berlin, DstPolicy::Earlier).unwrap().utc_offset, 2 * 60);
+        assert_eq!(TimePoint::new_in_zone_with(2024, 10, 27, 2, 30, 0, berlin, DstPolicy::Later).unwrap().utc_offset, 60);
+
+        assert_eq!(TimePoint::new_in_zone_with(2024, 3, 31, 2, 30, 0, berlin, DstPolicy::Later),
+            Err(TimePointError::NonExistentLocalTime { zone: berlin }));
+        let shifted = TimePoint::new_in_zone_with(2024, 3, 31, 2, 30, 0, berlin, DstPolicy::ShiftForward).unwrap();
+        assert_eq!(shifted.to_string(), "2024-03-31 03:30:00 UTC+02:00 Europe/Berlin");
+        // Samoa crossed the date line and skipped the whole of 2011-12-30
+        let apia = chrono_tz::Pacific::Apia;
+        let skipped = TimePoint::new_in_zone_with(2011, 12, 30, 10, 0, 0, apia, DstPolicy::ShiftForward).unwrap();
+        assert_eq!(skipped.to_string(), "2011-12-31 10:00:00 UTC+14:00 Pacific/Apia");
+    }
+
+    #[test]
+    fn test_to_zone() {
+        let mut origin: TimePoint = TimePoint::new(2024, 7, 1, 12, 0, 0, 8 * 60).unwrap();
+        origin.to_zone(chrono_tz::Europe::London).unwrap();
+        assert_eq!(origin.to_string(), "2024-07-01 05:00:00 UTC+01:00 Europe/London");
+        origin.to_utc(0).unwrap();
+        assert_eq!(origin.to_string(), "2024-07-01 04:00:00 UTC+00:00");
+
+        let mut far_future = TimePoint::new(300_000, 1, 1, 0, 0, 0, 0).unwrap();
+        assert_eq!(far_future.to_zone(chrono_tz::Europe::London), Err(TimePointError::OutOfRange));
+        assert_eq!(far_future.to_string(), "300000-01-01 00:00:00 UTC+00:00");
+        let mut far_future_date = TimePoint::new_date(300_000, 1, 1, 0).unwrap();
+        assert_eq!(far_future_date.to_zone(chrono_tz::Europe::London), Ok(()));
+        assert_eq!(far_future_date, TimePoint::new_date(300_000, 1, 1, 0).unwrap());
+    }
+
+    #[test]
+    fn test_zone_beyond_chrono_range() {
+        let london = chrono_tz::Europe::London;
+        assert_eq!(TimePoint::new_in_zone(300_000, 1, 1, 0, 0, 0, london), Err(TimePointError::OutOfRange));
+        assert_eq!(TimePoint::new_in_zone_with(300_000, 1, 1, 0, 0, 0, london, DstPolicy::ShiftForward), Err(TimePointError::OutOfRange));
+        assert_eq!(TimePoint::resolve_in_zone(-300_000, 1, 1, 0, 0, 0, london).err(), Some(TimePointError::OutOfRange));
+        let zoned = TimePoint::new_in_zone(2025, 6, 15, 9, 0, 0, london).unwrap();
+        assert_eq!(zoned.add_years(300_000), Err(TimePointError::OutOfRange));
+        // the fields are public, so a zoned value can still end up out of range
+        let far_future = TimePoint { zone: Some(london), ..TimePoint::new(300_000, 1, 1, 0, 0, 0, 0).unwrap() };
+        assert_eq!(far_future.end_of_month(), Err(TimePointError::OutOfRange));
+        assert_eq!(far_future.end_of_year(), Err(TimePointError::OutOfRange));
+        assert_eq!(far_future.with_precision(Precision::Day), Err(TimePointError::OutOfRange));
+    }
+
+    #[test]
+    fn test_add_in_zone_crosses_dst() {
+        let origin: TimePoint = TimePoint::new_in_zone(2024, 10, 1, 9, 0, 0, chrono_tz::Europe::London).unwrap();
+        let later = origin.checked_add(FlightDuration::from_hours(31 * 24)).unwrap();
+        assert_eq!(later.to_string(), "2024-11-01 08:00:00 UTC+00:00 Europe/London");
+        assert_eq!(later - origin, SignedFlightDuration::from_hours(31 * 24));
+    }
+
+    #[test]
+    fn test_offset_range_edges() {
+        assert!(TimePoint::new(2024, 5, 2, 8, 0, 0, 14 * 60).is_ok()); // CXI
+        assert!(TimePoint::new(2024, 5, 2, 8, 0, 0, 13 * 60).is_ok()); // TBU
+        assert!(TimePoint::new(2024, 5, 2, 8, 0, 0, -12 * 60).is_ok());
+        let tbu = TimePoint::new_in_zone(2024, 5, 2, 8, 0, 0, chrono_tz::Pacific::Tongatapu).unwrap();
+        assert_eq!(tbu.utc_offset, 13 * 60);
+        let cxi = TimePoint::new_in_zone(2024, 5, 2, 8, 0, 0, chrono_tz::Pacific::Kiritimati).unwrap();
+        assert_eq!(cxi.utc_offset, 14 * 60);
+    }
+
+    #[test]
+    fn test_to_utc_across_date_line() {
+        // CXI -> HNL lands on the calendar day before it departed
+        let departure = TimePoint::new(2024, 5, 2, 8, 0, 0, 14 * 60).unwrap();
+        let arrival = TimePoint::new(2024, 5, 1, 11, 0, 0, -10 * 60).unwrap();
+        assert!(arrival.is_after(&departure));
+        assert_eq!(arrival.calc_duration_with(&departure).as_seconds(), 3 * 3600);
+
+        let mut converted = departure.clone();
+        converted.to_utc(-10 * 60).unwrap();
+        assert_eq!(converted.to_string(), "2024-05-01 08:00:00 UTC-10:00");
+        converted.to_utc(14 * 60).unwrap();
+        assert_eq!(converted.to_string(), "2024-05-02 08:00:00 UTC+14:00");
+        converted.to_utc(-12 * 60).unwrap();
+        assert_eq!(converted.to_string(), "2024-05-01 06:00:00 UTC-12:00");
+    }
+
+    #[test]
+    fn test_parse_rfc3339() {
+        let tp: TimePoint = "2025-10-01T10:32:17+08:00".parse().unwrap();
+        assert_eq!(tp, TimePoint::new(2025, 10, 1, 10, 32, 17, 8 * 60).unwrap());
+        let utc: TimePoint = "2025-10-01T02:32:17Z".parse().unwrap();
+        assert_eq!(utc.utc_offset, 0);
+        let ktm: TimePoint = "2025-10-01T10:32:17+05:45".parse().unwrap();
+        assert_eq!(ktm.to_string(), "2025-10-01 10:32:17 UTC+05:45");
+    }
+
+    #[test]
+    fn test_rfc3339_round_trip() {
+        for text in ["2025-10-01T10:32:17+08:00", "1999-12-31T23:59:59-03:30", "2024-02-29T00:00:00+00:00"] {
+            let tp: TimePoint = text.parse().unwrap();
+            assert_eq!(tp.to_rfc3339(), text);
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!("2025-10-01 10:32".parse::<TimePoint>(), Err(TimePointError::InvalidFormat("2025-10-01 10:32".to_string())));
+        assert_eq!("yesterday".parse::<TimePoint>(), Err(TimePointError::InvalidFormat("yesterday".to_string())));
+        assert_eq!("2025-10-01T10:32:17.250+05:45".parse::<TimePoint>(), Err(TimePointError::InvalidFormat("2025-10-01T10:32:17.250+05:45".to_string())));
+        assert_eq!("2025-10-01T10:32:17+15:00".parse::<TimePoint>(), Err(TimePointError::OffsetOutOfRange(15 * 60)));
+    }
+
+    #[test]
+    fn test_parse_local() {
+        let lhr = TimePoint::parse_local("2025-07-01 10:32", chrono_tz::Europe::London).unwrap();
+        assert_eq!(lhr.to_rfc3339(), "2025-07-01T10:32:00+01:00");
+        let pek = TimePoint::parse_local(" 2025/10/01 10:32:17 ", chrono_tz::Asia::Shanghai).unwrap();
+        assert_eq!(pek.to_rfc3339(), "2025-10-01T10:32:17+08:00");
+        assert_eq!(TimePoint::parse_local("01.10.2025", chrono_tz::Asia::Shanghai), Err(TimePointError::InvalidFormat("01.10.2025".to_string())));
+    }
+
+    #[test]
+    fn test_from_chrono() {
+        let fixed = DateTime::parse_from_rfc3339("2025-10-01T10:32:17+05:30").unwrap();
+        assert_eq!(TimePoint::try_from(fixed).unwrap().to_string(), "2025-10-01 10:32:17 UTC+05:30");
+        let utc = Utc.with_ymd_and_hms(2025, 10, 1, 2, 32, 17).unwrap();
+        assert_eq!(TimePoint::from(utc).to_string(), "2025-10-01 02:32:17 UTC+00:00");
+        let zoned = chrono_tz::Asia::Shanghai.with_ymd_and_hms(2025, 10, 1, 10, 32, 17).unwrap();
+        assert_eq!(TimePoint::try_from(zoned).unwrap().to_string(), "2025-10-01 10:32:17 UTC+08:00 Asia/Shanghai");
+        let too_far = FixedOffset::east_opt(16 * 3600).unwrap().with_ymd_and_hms(2025, 10, 1, 0, 0, 0).unwrap();
+        assert_eq!(TimePoint::try_from(too_far), Err(TimePointError::OffsetOutOfRange(16 * 60)));
+    }
+
+    #[test]
+    fn test_to_chrono() {
+        let tp = TimePoint::new_in_zone(2025, 7, 1, 10, 32, 17, chrono_tz::Europe::London).unwrap();
+        let fixed = DateTime::<FixedOffset>::try_from(&tp).unwrap();
+        assert_eq!(fixed.to_rfc3339(), "2025-07-01T10:32:17+01:00");
+        let utc = DateTime::<Utc>::try_from(&tp).unwrap();
+        assert_eq!(utc.to_rfc3339(), "2025-07-01T09:32:17+00:00");
+        let zoned = DateTime::<Tz>::try_from(&tp).unwrap();
+        assert_eq!(zoned.timezone(), chrono_tz::Europe::London);
+        assert_eq!(TimePoint::try_from(zoned).unwrap(), tp);
+
+        let fixed_only = TimePoint::new(2025, 7, 1, 10, 32, 17, 60).unwrap();
+        assert_eq!(DateTime::<Tz>::try_from(&fixed_only), Err(TimePointError::MissingZone));
+        let far_future = TimePoint::new(300_000, 1, 1, 0, 0, 0, 0).unwrap();
+        assert_eq!(DateTime::<FixedOffset>::try_from(&far_future), Err(TimePointError::OutOfRange));
+        assert_eq!(DateTime::<Utc>::try_from(&far_future), Err(TimePointError::OutOfRange));
+    }
+
+    #[test]
+    fn test_partial_constructors() {
+        let month = TimePoint::new_month(2009, 3, 8 * 60).unwrap();
+        assert_eq!(month.precision, Precision::Month);
+        assert_eq!(month.to_string(), "2009-03");
+        assert_eq!(TimePoint::new_year(1998, 8 * 60).unwrap().to_string(), "1998");
+        assert_eq!(TimePoint::new_date(2009, 3, 15, 8 * 60).unwrap().to_string(), "2009-03-15");
+        assert_eq!(TimePoint::new_month(2009, 13, 8 * 60), Err(TimePointError::InvalidMonth(13)));
+    }
+
+    #[test]
+    fn test_with_precision_truncates() {
+        let exact = TimePoint::new(2009, 3, 15, 10, 32, 17, 8 * 60).unwrap();
+        assert_eq!(exact.clone().with_precision(Precision::Minute).unwrap().to_string(), "2009-03-15 10:32 UTC+08:00");
+        let day = exact.clone().with_precision(Precision::Day).unwrap();
+        assert_eq!((day.hour, day.minute, day.second), (0, 0, 0));
+        // never regains precision once dropped
+        assert_eq!(day.with_precision(Precision::Second).unwrap().precision, Precision::Day);
+        // clocks in Asuncion jumped from midnight to 01:00 on 2015-10-04
+        let asuncion = chrono_tz::America::Asuncion;
+        let gap_day = TimePoint::new_in_zone(2015, 10, 4, 12, 0, 0, asuncion).unwrap();
+        assert_eq!(gap_day.clone().with_precision(Precision::Day), Err(TimePointError::NonExistentLocalTime { zone: asuncion }));
+        assert_eq!(gap_day.with_precision_with(Precision::Day, DstPolicy::ShiftForward).unwrap().to_rfc3339(), "2015-10-04T01:00:00-03:00");
+        // Havana turns its clocks back from 01:00 to midnight, so midnight comes twice
+        let havana = chrono_tz::America::Havana;
+        let fall_back = TimePoint::new_in_zone(2024, 11, 3, 12, 0, 0, havana).unwrap();
+        assert_eq!(fall_back.clone().with_precision(Precision::Day),
+            Err(TimePointError::AmbiguousLocalTime { zone: havana, earlier_offset: -4 * 60, later_offset: -5 * 60 }));
+        assert_eq!(fall_back.with_precision_with(Precision::Day, DstPolicy::Earlier).unwrap().utc_offset, -4 * 60);
+        let parsed = TimePoint::parse_local("2025-07-01 10:32", chrono_tz::Europe::London).unwrap();
+        assert_eq!(parsed.to_string(), "2025-07-01 10:32 UTC+01:00 Europe/London");
+    }
+
+    #[test]
+    fn test_conversions_keep_coarse_dates() {
+        let original = TimePoint::new_date(2009, 3, 15, 8 * 60).unwrap();
+        let mut date = original.clone();
+        date.to_utc(0).unwrap();
+        assert_eq!(date, original);
+        date.to_zone(chrono_tz::America::New_York).unwrap();
+        assert_eq!((date.year, date.month, date.day, date.hour, date.utc_offset, date.zone), (2009, 3, 15, 0, 8 * 60, None));
+        assert_eq!(date.to_string(), "2009-03-15");
+        // still sorts where it did, after the evening before in Beijing
+        let evening_before = TimePoint::new(2009, 3, 14, 23, 0, 0, 8 * 60).unwrap();
+        assert_eq!(date.cmp(&evening_before), original.cmp(&evening_before));
+        assert!(date.is_after(&evening_before));
+
+        let next_day = date.checked_add(FlightDuration::from_hours(36)).unwrap();
+        assert_eq!((next_day.day, next_day.hour, next_day.precision), (16, 0, Precision::Day));
+        let month = TimePoint::new_month(2009, 3, 8 * 60).unwrap().checked_sub(FlightDuration::from_hours(1)).unwrap();
+        assert_eq!(month.to_string(), "2009-02");
+    }
+
+    #[test]
+    fn test_partial_comparisons() {
+        let march = TimePoint::new_month(2009, 3, 8 * 60).unwrap();
+        let mid_march = TimePoint::new(2009, 3, 15, 10, 0, 0, 8 * 60).unwrap();
+        let april = TimePoint::new(2009, 4, 1, 0, 0, 0, 8 * 60).unwrap();
+        let february = TimePoint::new_date(2009, 2, 28, 8 * 60).unwrap();
+        assert!(!march.is_before(&mid_march));
+        assert!(!march.is_after(&mid_march));
+        assert!(march.overlaps(&mid_march));
+        assert!(march.is_before(&april));
+        assert!(february.is_before(&march));
+        assert!(march.is_after(&february));
+
+        // same start but different knowledge is not the same value
+        let first_of_march = TimePoint::new(2009, 3, 1, 0, 0, 0, 8 * 60).unwrap();
+        assert_ne!(march, first_of_march);
+        let mut sorted = vec![first_of_march.clone(), march.clone()];
+        sorted.sort();
+        assert_eq!(sorted, vec![march, first_of_march]);
+    }
+
+    #[test]
+    fn test_add_months_clamps_to_month_end() {
+        let jan_31 = TimePoint::new(2024, 1, 31, 10, 0, 0, 8 * 60).unwrap();
+        assert_eq!(jan_31.add_months(1).unwrap().to_string(), "2024-02-29 10:00:00 UTC+08:00");
+        assert_eq!(jan_31.add_months(13).unwrap().to_string(), "2025-02-28 10:00:00 UTC+08:00");
+        assert_eq!(jan_31.add_months(2).unwrap().to_string(), "2024-03-31 10:00:00 UTC+08:00");
+        assert_eq!(jan_31.sub_months(2).unwrap().to_string(), "2023-11-30 10:00:00 UTC+08:00");
+        let mar_31 = TimePoint::new(2024, 3, 31, 10, 0, 0, 8 * 60).unwrap();
+        assert_eq!(mar_31.sub_months(1).unwrap().to_string(), "2024-02-29 10:00:00 UTC+08:00");
+        assert_eq!(mar_31.add_months(-15).unwrap().to_string(), "2022-12-31 10:00:00 UTC+08:00");
+    }
+
+    #[test]
+    fn test_add_years() {
+        let leap_day = TimePoint::new(2024, 2, 29, 18, 45, 0, 0).unwrap();
+        assert_eq!(leap_day.add_years(1).unwrap().to_string(), "2025-02-28 18:45:00 UTC+00:00");
+        assert_eq!(leap_day.add_years(4).unwrap().to_string(), "2028-02-29 18:45:00 UTC+00:00");
+        assert_eq!(leap_day.sub_years(1).unwrap().to_string(), "2023-02-28 18:45:00 UTC+00:00");
+        // ticket valid for 12 months
+        let issued = TimePoint::new(2025, 10, 1, 10, 32, 17, 8 * 60).unwrap();
+        assert_eq!(issued.add_months(12).unwrap(), issued.add_years(1).unwrap());
+        assert_eq!(issued.add_years(i32::MAX), Err(TimePointError::OutOfRange));
+        assert_eq!(issued.sub_years(i32::MIN), Err(TimePointError::OutOfRange));
+        assert_eq!(issued.sub_months(i32::MIN), Err(TimePointError::OutOfRange));
+        let last_year = TimePoint::new(i32::MAX, 12, 1, 0, 0, 0, 0).unwrap();
+        assert_eq!(last_year.add_months(1), Err(TimePointError::OutOfRange));
+    }
+
+    #[test]
+    fn test_calendar_arithmetic_in_zone_keeps_wall_clock() {
+        let berlin = chrono_tz::Europe::Berlin;
+        let winter = TimePoint::new_in_zone(2024, 3, 1, 10, 0, 0, berlin).unwrap();
+        let spring = winter.add_months(1).unwrap();
+        assert_eq!(spring.to_string(), "2024-04-01 10:00:00 UTC+02:00 Europe/Berlin");
+        let gap_night = TimePoint::new_in_zone(2024, 1, 31, 2, 30, 0, berlin).unwrap();
+        assert_eq!(gap_night.add_months(2), Err(TimePointError::NonExistentLocalTime { zone: berlin }));
+    }
+
+    #[test]
+    fn test_end_of_month_and_year() {
+        let earned = TimePoint::new(2025, 6, 15, 9, 0, 0, 8 * 60).unwrap();
+        assert_eq!(earned.add_years(1).unwrap().end_of_year().unwrap().to_string(), "2026-12-31 23:59:59 UTC+08:00");
+        assert_eq!(earned.end_of_month().unwrap().to_string(), "2025-06-30 23:59:59 UTC+08:00");
+        let rough = TimePoint::parse_local("2025-06-15 09:00", chrono_tz::Asia::Shanghai).unwrap();
+        assert_eq!(rough.end_of_year().unwrap().to_string(), "2025-12-31 23:59 UTC+08:00 Asia/Shanghai");
+        let march = TimePoint::new_month(2009, 3, 8 * 60).unwrap();
+        assert_eq!(march.add_months(1).unwrap().to_string(), "2009-04");
+        assert_eq!(march.end_of_month().unwrap().to_string(), "2009-03-31");
+        assert_eq!(TimePoint::new_year(2009, 8 * 60).unwrap().end_of_year().unwrap().hour, 0);
     }
 
     #[test]
     fn test_after() {
-        let mut origin: TimePoint = TimePoint::new(2025, 10, 1, 10, 32, 17, 8);
-        origin.after(0, 5, 0, 0, 0, 0); // test month overflow
-        assert_eq!(origin.to_string(), "2026-03-01 10:32:17 UTC+8");
-        origin.after(0, 0, 0, 15, 0, 0); // test hour overflow
-        assert_eq!(origin.to_string(), "2026-03-02 01:32:17 UTC+8");
-        origin.after(0, 0, 40, 0, 0, 0); // test day overflow
-        assert_eq!(origin.to_string(), "2026-04-11 01:32:17 UTC+8");
-        origin.after(0, 0, 0, 0, 30, 0); // test minute overflow
-        assert_eq!(origin.to_string(), "2026-04-11 02:02:17 UTC+8");
-        origin.after(0, 0, 0, 0, 0, 50); // test second overflow
-        assert_eq!(origin.to_string(), "2026-04-11 02:03:07 UTC+8");
-        origin.after(0, 0, 0, 0, 0, 0); // test nothing changed
-        assert_eq!(origin.to_string(), "2026-04-11 02:03:07 UTC+8");
-        origin.after(-1, -1, -1, -1, -1, -1); // test error exception
-        assert_eq!(origin.to_string(), "2026-04-11 02:03:07 UTC+8");
+        let mut origin: TimePoint = TimePoint::new(2025, 10, 1, 10, 32, 17, 8 * 60).unwrap();
+        origin = origin.add_months(5).unwrap(); // test month overflow
+        assert_eq!(origin.to_string(), "2026-03-01 10:32:17 UTC+08:00");
+        origin = origin.checked_add(TimeDelta::hours(15)).unwrap(); // test hour overflow
+        assert_eq!(origin.to_string(), "2026-03-02 01:32:17 UTC+08:00");
+        origin = origin.checked_add(TimeDelta::days(40)).unwrap(); // test day overflow
+        assert_eq!(origin.to_string(), "2026-04-11 01:32:17 UTC+08:00");
+        origin = origin.checked_add(TimeDelta::minutes(30)).unwrap(); // test minute overflow
+        assert_eq!(origin.to_string(), "2026-04-11 02:02:17 UTC+08:00");
+        origin = origin.checked_add(TimeDelta::seconds(50)).unwrap(); // test second overflow
+        assert_eq!(origin.to_string(), "2026-04-11 02:03:07 UTC+08:00");
+        origin = origin.checked_add(TimeDelta::zero()).unwrap(); // test nothing changed
+        assert_eq!(origin.to_string(), "2026-04-11 02:03:07 UTC+08:00");
+        assert_eq!(origin.checked_add(SignedFlightDuration::from_seconds(i64::MAX)), Err(TimePointError::OutOfRange)); // test error exception
+        assert_eq!(origin.to_string(), "2026-04-11 02:03:07 UTC+08:00");
+        let year_end = TimePoint::new(2025, 12, 31, 23, 0, 0, 5 * 60 + 45).unwrap().checked_add(TimeDelta::hours(1)).unwrap();
+        assert_eq!(year_end.to_string(), "2026-01-01 00:00:00 UTC+05:45");
     }
 
     #[test]
     fn test_before() {
-        let mut origin: TimePoint = TimePoint::new(2025, 10, 1, 10, 32, 17, 8);
-        origin.before(0, 12, 0, 0, 0, 0); // test month overflow
-        assert_eq!(origin.to_string(), "2024-10-01 10:32:17 UTC+8");
-        origin.before(0, 0, 2, 0, 0, 0); // test day overflow
-        assert_eq!(origin.to_string(), "2024-09-29 10:32:17 UTC+8");
-        origin.before(0, 0, 0, 12, 0, 0); // test hour overflow
-        assert_eq!(origin.to_string(), "2024-09-28 22:32:17 UTC+8");
-        origin.before(0, 0, 0, 0, 40, 0); // test minute overflow
-        assert_eq!(origin.to_string(), "2024-09-28 21:52:17 UTC+8");
-        origin.before(0, 0, 0, 0, 0, 20); // test second overflow
-        assert_eq!(origin.to_string(), "2024-09-28 21:51:57 UTC+8");
-    }
-
-    #[test] // this test only valid when your system time zone at UTC+8
-    fn test_get_now_utc() {
-        let mut test = TimePoint::new(0, 0, 0, 0, 0, 0, 0);
-        test.get_now();
-        assert_eq!(test.utc_offset, 8);
+        let mut origin: TimePoint = TimePoint::new(2025, 10, 1, 10, 32, 17, 8 * 60).unwrap();
+        origin = origin.sub_months(12).unwrap(); // test month overflow
+        assert_eq!(origin.to_string(), "2024-10-01 10:32:17 UTC+08:00");
+        origin = origin.checked_sub(TimeDelta::days(2)).unwrap(); // test day overflow
+        assert_eq!(origin.to_string(), "2024-09-29 10:32:17 UTC+08:00");
+        origin = origin.checked_sub(TimeDelta::hours(12)).unwrap(); // test hour overflow
+        assert_eq!(origin.to_string(), "2024-09-28 22:32:17 UTC+08:00");
+        origin = origin.checked_sub(TimeDelta::minutes(40)).unwrap(); // test minute overflow
+        assert_eq!(origin.to_string(), "2024-09-28 21:52:17 UTC+08:00");
+        origin = origin.checked_sub(TimeDelta::seconds(20)).unwrap(); // test second overflow
+        assert_eq!(origin.to_string(), "2024-09-28 21:51:57 UTC+08:00");
+        assert_eq!(origin.checked_sub(FlightDuration::from_hours(24 * 365 * 300_000)), Err(TimePointError::OutOfRange));
+        assert_eq!(origin.checked_sub(TimeDelta::milliseconds(500)), Err(TimePointError::InvalidDuration));
+        let leap_day = TimePoint::new(2024, 3, 1, 0, 30, 0, 0).unwrap().checked_sub(TimeDelta::hours(1)).unwrap();
+        assert_eq!(leap_day.to_string(), "2024-02-29 23:30:00 UTC+00:00");
+    }
+
+    #[test]
+    fn test_after_in_zone_crosses_dst() {
+        let origin: TimePoint = TimePoint::new_in_zone(2024, 10, 1, 9, 0, 0, chrono_tz::Europe::London).unwrap();
+        assert_eq!(origin.add_months(1).unwrap().to_string(), "2024-11-01 09:00:00 UTC+00:00 Europe/London");
+    }
+
+    #[test]
+    fn test_add_flight_duration() {
+        let departure = TimePoint::new(2025, 10, 1, 13, 0, 0, 8 * 60).unwrap();
+        let block_time = FlightDuration::from_hours_minutes(11, 5);
+        let arrival = departure.checked_add(block_time).unwrap();
+        assert_eq!(arrival.to_string(), "2025-10-02 00:05:00 UTC+08:00");
+        assert_eq!(arrival.calc_duration_with(&departure), block_time);
+        assert_eq!(arrival.checked_sub(SignedFlightDuration::from_minutes(-5)).unwrap(), TimePoint::new(2025, 10, 2, 0, 10, 0, 8 * 60).unwrap());
+        assert_eq!(&departure + block_time, arrival);
+        assert_eq!(&arrival - SignedFlightDuration::from_minutes(-5), TimePoint::new(2025, 10, 2, 0, 10, 0, 8 * 60).unwrap());
+    }
+
+    #[test]
+    fn test_duration_operators() {
+        let mut origin: TimePoint = TimePoint::new(2025, 10, 1, 10, 32, 17, 8 * 60).unwrap();
+        origin += TimeDelta::hours(15); // test hour overflow
+        assert_eq!(origin.to_string(), "2025-10-02 01:32:17 UTC+08:00");
+        origin -= TimeDelta::days(2); // test day overflow
+        assert_eq!(origin.to_string(), "2025-09-30 01:32:17 UTC+08:00");
+        origin += FlightDuration::from_minutes(30);
+        assert_eq!(origin.to_string(), "2025-09-30 02:02:17 UTC+08:00");
+        origin -= SignedFlightDuration::from_seconds(-50);
+        assert_eq!(origin.to_string(), "2025-09-30 02:03:07 UTC+08:00");
+        let year_end = TimePoint::new(2025, 12, 31, 23, 0, 0, 5 * 60 + 45).unwrap() + TimeDelta::hours(1);
+        assert_eq!(year_end.to_string(), "2026-01-01 00:00:00 UTC+05:45");
+        let leap_day = TimePoint::new(2024, 3, 1, 0, 30, 0, 0).unwrap() - TimeDelta::hours(1);
+        assert_eq!(leap_day.to_string(), "2024-02-29 23:30:00 UTC+00:00");
+    }
+
+    #[test]
+    #[should_panic(expected = "time point is out of the supported range")]
+    fn test_duration_operator_panics_out_of_range() {
+        let _ = TimePoint::new(2025, 10, 1, 10, 32, 17, 8 * 60).unwrap() + SignedFlightDuration::from_seconds(i64::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "duration is not a whole number of seconds")]
+    fn test_duration_operator_panics_on_fractional_seconds() {
+        let mut origin = TimePoint::new(2025, 10, 1, 10, 32, 17, 8 * 60).unwrap();
+        origin -= TimeDelta::milliseconds(500);
+    }
+
+    #[test]
+    fn test_same_instant_in_different_offsets_is_equal() {
+        let pek = TimePoint::new(2025, 10, 1, 10, 0, 0, 8 * 60).unwrap();
+        let lhr = TimePoint::new(2025, 10, 1, 3, 0, 0, 60).unwrap();
+        assert_eq!(pek, lhr);
+        assert_eq!(pek.cmp(&lhr), Ordering::Equal);
+
+        let mut seen = std::collections::HashSet::new();
+        seen.insert(pek);
+        assert!(seen.contains(&lhr));
+    }
+
+    #[test]
+    fn test_sort_by_instant() {
+        let first = TimePoint::new(2025, 10, 1, 10, 0, 0, 8 * 60).unwrap();
+        let second = TimePoint::new(2025, 10, 1, 4, 0, 0, 60).unwrap();
+        let third = TimePoint::new(2025, 9, 30, 23, 30, 0, -5 * 60).unwrap();
+        let mut flights = vec![third.clone(), first.clone(), second.clone()];
+        flights.sort();
+        assert_eq!(flights, vec![first.clone(), second.clone(), third.clone()]);
+
+        let mut log = std::collections::BTreeMap::new();
+        log.insert(third, "CA981");
+        log.insert(first, "CA937");
+        assert_eq!(log.values().copied().collect::<Vec<_>>(), vec!["CA937", "CA981"]);
     }
 }
@@ -0,0 +1,5 @@
+// most of the domain model is still a prototype and not wired into the menu yet,
+// main.rs only drives the menu on top of this library
+pub mod domain;
+pub mod prompt;
+pub mod version;
@@ -1,12 +1,38 @@
-mod version;
-mod domain;
+use std::io::{self, BufRead};
+
+use flight_travel_record::{domain::airport_registry::AirportRegistry, prompt, version};
 
 fn main() {
     info_page();
+    let stdin = io::stdin();
+    let mut input = stdin.lock();
+    let mut choice = String::new();
+    if input.read_line(&mut choice).is_err() {
+        return;
+    }
+    match choice.trim() {
+        "1" => look_up_airport(&mut input),
+        _ => println!("Unknown feature."),
+    }
 }
 
 fn info_page() {
     println!("Flight Travel Record v{}", version::get_version());
     println!();
     println!("Enter the number below to use features:");
+    println!("1. Look up an airport");
+}
+
+fn look_up_airport<R: BufRead>(input: &mut R) {
+    let registry = AirportRegistry::bundled();
+    match prompt::prompt_airport(&registry, input, &mut io::stdout()) {
+        Ok(Some(airport)) => {
+            let coordinates = airport.get_coordinates();
+            println!("{} {}", airport.get_code(), airport.get_name());
+            println!("{}, {}", airport.get_city(), airport.get_country());
+            println!("{:.4}, {:.4}, {} ft", coordinates.latitude, coordinates.longitude, airport.get_elevation());
+        },
+        Ok(None) => {},
+        Err(error) => println!("Cannot read input: {}", error),
+    }
 }
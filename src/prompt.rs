@@ -0,0 +1,72 @@
+// interactive prompts of the command line menu, reading from any BufRead so they can be driven by tests
+
+use std::io::{self, BufRead, Write};
+
+use crate::domain::{airport::Airport, airport_registry::AirportRegistry, airport_search};
+
+const MAX_CANDIDATES: usize = 9;
+
+// asks until an airport is picked, None when the user enters nothing
+pub fn prompt_airport<'a, R: BufRead, W: Write>(registry: &'a AirportRegistry, input: &mut R, output: &mut W) -> io::Result<Option<&'a Airport>> {
+    loop {
+        write!(output, "Airport (code, city or name): ")?;
+        output.flush()?;
+        let query = read_line(input)?;
+        if query.is_empty() {
+            return Ok(None);
+        }
+        let candidates = match airport_search::resolve(registry, &query, MAX_CANDIDATES) {
+            Ok(airport) => return Ok(Some(airport)),
+            Err(candidates) => candidates,
+        };
+        if candidates.is_empty() {
+            writeln!(output, "No airport matches \"{}\".", query)?;
+            continue;
+        }
+        for (index, candidate) in candidates.iter().enumerate() {
+            writeln!(output, "{}. {}", index + 1, candidate.label())?;
+        }
+        write!(output, "Choose 1-{} (Enter to search again): ", candidates.len())?;
+        output.flush()?;
+        let choice = read_line(input)?;
+        if let Ok(number) = choice.parse::<usize>()
+            && (1..=candidates.len()).contains(&number) {
+            return Ok(Some(candidates[number - 1].airport));
+        }
+    }
+}
+
+fn read_line<R: BufRead>(input: &mut R) -> io::Result<String> {
+    let mut line = String::new();
+    input.read_line(&mut line)?;
+    return Ok(line.trim().to_string());
+}
+
+#[cfg(test)]
+mod test_prompt {
+    use super::*;
+
+    fn run(registry: &AirportRegistry, typed: &str) -> (Option<String>, String) {
+        let mut output = Vec::new();
+        let airport = prompt_airport(registry, &mut typed.as_bytes(), &mut output).unwrap();
+        return (airport.map(|airport| airport.get_iata_code().to_string()), String::from_utf8(output).unwrap());
+    }
+
+    #[test]
+    fn test_prompt_airport() {
+        let registry = AirportRegistry::bundled();
+        assert_eq!(run(&registry, "pek\n").0, Some("PEK".to_string()));
+
+        let (airport, output) = run(&registry, "shanghai\n2\n");
+        assert_eq!(airport, Some("SHA".to_string()));
+        assert!(output.contains("1. Shanghai (PVG) - Shanghai Pudong International Airport, China\n"));
+        assert!(output.contains("Choose 1-2"));
+
+        let (airport, output) = run(&registry, "qqqqqq\nheathrw\n1\n");
+        assert_eq!(airport, Some("LHR".to_string()));
+        assert!(output.contains("No airport matches \"qqqqqq\"."));
+
+        assert_eq!(run(&registry, "\n").0, None);
+        assert_eq!(run(&registry, "").0, None);
+    }
+}